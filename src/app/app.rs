@@ -1,6 +1,12 @@
+use std::{
+    sync::mpsc::Receiver,
+    thread,
+    time::{Duration, Instant},
+};
+
 use color_eyre::Result;
 use ratatui::{
-    crossterm::event::{self, Event, KeyCode, KeyEvent},
+    crossterm::event::{self, Event, KeyEvent, KeyEventKind},
     layout::{Constraint, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
@@ -10,217 +16,245 @@ use ratatui::{
 };
 
 use crate::app::bookmark::bookmark::Bookmarks;
+use crate::app::config::{Config, TabKind};
+use crate::app::keymap::{Action, Context, Keymap, Resolved};
+use crate::app::notification::{Level, Notification, Notifier};
 use crate::app::schedule::schedule::Schedules;
+use crate::app::tab::TabApp;
 use crate::app::task::task::Tasks;
+use crate::app::ui::{
+    account_switcher::AccountSwitcher,
+    help::{Help, HelpItem, HelpSection},
+    message_log::MessageLog,
+};
 use crate::app::virtualbox::virtualbox::VirtualBox;
-use crate::app::google::authentication::TokenInfo;
+use crate::app::google::account::Accounts;
 
-#[derive(Debug)]
+const TICK_RATE: Duration = Duration::from_millis(100);
+const TOAST_DURATION: Duration = Duration::from_secs(5);
+const MESSAGE_LOG_LIMIT: usize = 500;
+
+#[derive(Debug, PartialEq)]
 pub enum WindowMode {
     Bookmark,
     Tab,
 }
 
-#[derive(Debug, Clone)]
-pub enum TabMode {
-    Schedule,
-    Tasks,
-    VirtualBox,
-}
-
-impl From<TabMode> for Option<usize> {
-    fn from(tab: TabMode) -> Self {
-        match tab {
-            TabMode::Schedule => Some(0),
-            TabMode::Tasks => Some(1),
-            TabMode::VirtualBox => Some(2),
-        }
-    }
-}
-
-#[derive(Debug)]
 pub struct App<'a> {
     window_mode: WindowMode,
-    selected_tab: TabMode,
-    tab_labels: Vec<Line<'static>>,
+    selected_tab: usize,
     bookmarks: Bookmarks<'a>,
-    schedules: Schedules<'a>,
-    tasks: Tasks<'a>,
-    virtualbox: VirtualBox<'a>,
-    token_info: TokenInfo,
+    tabs: Vec<Box<dyn TabApp + 'a>>,
+    help: Help,
+    keymap: Keymap,
+    accounts: Accounts,
+    account_switcher: AccountSwitcher<'a>,
+    notifier: Notifier,
+    notifications: Receiver<Notification>,
+    messages: Vec<Notification>,
+    message_log: MessageLog,
+    toast: Option<(Notification, Instant)>,
 }
 
 impl<'a> App<'a> {
-    pub fn new(token_info: &TokenInfo) -> Self {
+    pub fn new(config: &Config, accounts: Accounts) -> Self {
+        let (notifier, notifications) = Notifier::new();
+
+        let tabs = config.tabs.iter().map(|tab| -> Box<dyn TabApp + 'a> {
+            match tab {
+                TabKind::Schedule => Box::new(Schedules::new(config, &accounts, &notifier)),
+                TabKind::Task => Box::new(Tasks::new(config, &accounts, &notifier)),
+                TabKind::VirtualBox => Box::new(VirtualBox::new(config, &notifier)),
+            }
+        })
+        .collect();
+
         Self {
             window_mode: WindowMode::Tab,
-            selected_tab: TabMode::Schedule,
-            tab_labels: vec![
-                Line::from("Schedule"),
-                Line::from("Task"),
-                Line::from("VirtualBox"),
-            ],
-            bookmarks: Bookmarks::new(),
-            schedules: Schedules::new(token_info), 
-            tasks: Tasks::new(token_info),
-            virtualbox: VirtualBox::new(),
-            token_info: token_info.clone(),
+            selected_tab: 0,
+            bookmarks: Bookmarks::new(config, &notifier),
+            tabs,
+            help: Help::new(""),
+            keymap: Keymap::new(&config.keymap),
+            accounts,
+            account_switcher: AccountSwitcher::new(),
+            notifier,
+            notifications,
+            messages: Vec::new(),
+            message_log: MessageLog::new(),
+            toast: None,
         }
     }
 
     pub fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
-        self.schedules.pane.active = true;
-        
+        self.bookmarks.refresh();
+        self.tabs.iter_mut().for_each(|tab| tab.refresh());
+
+        self.active_pane();
+
+        let mut last_tick = Instant::now();
         loop {
             terminal.draw(|frame| self.draw(frame))?;
 
-            if let Event::Key(key) = event::read()? {
-                self.change_mode(key);
-                
-                match self.window_mode {
-                    WindowMode::Bookmark => match key.code {
-                        KeyCode::Tab => {
-                            if self.bookmarks.form.popup.active {
-                                if self.bookmarks.form.title.active {
-                                    self.bookmarks.form.active_url()
-                                } else if self.bookmarks.form.url.active {
-                                    self.bookmarks.form.active_title()
-                                }
-                            }
-                        }
-                        KeyCode::Esc => {
-                            if self.bookmarks.help.popup.active {
-                                self.bookmarks.help.popup.active = false;
-                            } else if self.bookmarks.form.popup.active {
-                                self.bookmarks.form.popup.active = false;
-                            } else {
-                                break
-                            }
-                        },
-                        _ => self.bookmarks.key_binding(key),
+            let timeout = TICK_RATE.saturating_sub(last_tick.elapsed());
+            if event::poll(timeout)? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press && !self.on_key(key) {
+                        break;
                     }
-                    WindowMode::Tab => match self.selected_tab {
-                        TabMode::Schedule => match key.code {
-                            KeyCode::Tab => {
-                                if !self.schedules.help.popup.active & !self.schedules.form.popup.active {
-                                    self.selected_tab = TabMode::Tasks;
-                                }
-
-                                if self.schedules.form.popup.active {
-                                    if self.schedules.form.summary.active {
-                                        self.schedules.form.active_start();
-                                    } else if self.schedules.form.start.active {
-                                        self.schedules.form.active_end();
-                                    } else if self.schedules.form.end.active {
-                                        self.schedules.form.active_description();
-                                    } else if self.schedules.form.description.active {
-                                        self.schedules.form.active_summary();
-                                    }
-                                }
-                            },
-                            KeyCode::Esc => {
-                                if self.schedules.help.popup.active {
-                                    self.schedules.help.popup.active = false;
-                                } else if self.schedules.form.popup.active {
-                                    self.schedules.form.popup.active = false;
-                                } else {
-                                    break
-                                }
-                            }
-                            _ => self.schedules.key_binding(key, &self.token_info),
-                        }
-                        TabMode::Tasks => match key.code {
-                            KeyCode::Tab => {
-                                if !self.tasks.help.popup.active & !self.tasks.form.popup.active {
-                                    self.selected_tab = TabMode::VirtualBox;
-                                }
-
-                                if self.tasks.form.popup.active {
-                                    if self.tasks.form.title.active {
-                                        self.tasks.form.active_notes();
-                                    } else if self.tasks.form.notes.active {
-                                        self.tasks.form.active_due();
-                                    } else if self.tasks.form.due.active {
-                                        self.tasks.form.active_title();
-                                    }
-                                }
-                            },
-                            KeyCode::Esc => {
-                                if self.tasks.help.popup.active {
-                                    self.tasks.help.popup.active = false;
-                                } else if self.tasks.form.popup.active {
-                                    self.tasks.form.popup.active = false;
-                                } else {
-                                    break
-                                }
-                            }
-                            _ => self.tasks.key_binding(key, &self.token_info),
-                        }
-                        TabMode::VirtualBox => match key.code {
-                            KeyCode::Tab => {
-                                if !self.virtualbox.help.popup.active {
-                                    self.selected_tab = TabMode::Schedule;
-                                }
-                            },
-                            KeyCode::Esc => {
-                                if self.virtualbox.help.popup.active {
-                                    self.virtualbox.help.popup.active = false;
-                                } else {
-                                    break
-                                }
-                            },
-                            _ => self.virtualbox.key_binding(key),
-                        }
-                    },
                 }
-            
-                self.active_pane();
+            }
+
+            if last_tick.elapsed() >= TICK_RATE {
+                self.tick();
+                last_tick = Instant::now();
             }
         }
 
         Ok(())
     }
 
-    fn change_mode(&mut self, key: KeyEvent) {
-        match key.code {
-            KeyCode::F(5) => {
+    fn tick(&mut self) {
+        self.bookmarks.tick();
+        self.tabs.iter_mut().for_each(|tab| tab.tick());
+
+        while let Ok(notification) = self.notifications.try_recv() {
+            self.toast = Some((notification.clone(), Instant::now()));
+            self.messages.push(notification);
+        }
+        if self.messages.len() > MESSAGE_LOG_LIMIT {
+            self.messages.drain(..self.messages.len() - MESSAGE_LOG_LIMIT);
+        }
+        if self.toast.as_ref().is_some_and(|(_, shown)| shown.elapsed() >= TOAST_DURATION) {
+            self.toast = None;
+        }
+    }
+
+    fn on_key(&mut self, key: KeyEvent) -> bool {
+        let context = if self.message_log.popup.active || self.account_switcher.popup.active {
+            Context::List
+        } else if self.help.popup.active {
+            self.help.context()
+        } else {
+            self.focused().context()
+        };
+
+        match self.keymap.resolve(context, key) {
+            Resolved::Action(Action::Quit) => {
+                if self.message_log.popup.active {
+                    self.message_log.popup.active = false;
+                } else if self.account_switcher.popup.active {
+                    self.account_switcher.popup.active = false;
+                } else if self.help.popup.active {
+                    self.help.back();
+                } else if self.focused().has_open_popup() {
+                    self.focused().close_popup();
+                } else {
+                    return false;
+                }
+            },
+            Resolved::Action(action) => self.on_action(action),
+            Resolved::Unbound if self.message_log.popup.active || self.account_switcher.popup.active => {},
+            Resolved::Unbound => {
+                if self.help.popup.active {
+                    self.help.on_input(key);
+                } else {
+                    self.focused().on_input(key);
+                }
+            },
+            Resolved::Pending => {},
+        }
+
+        self.active_pane();
+
+        true
+    }
+
+    fn on_action(&mut self, action: Action) {
+        match action {
+            Action::Messages => {
+                if self.message_log.popup.active {
+                    self.message_log.popup.active = false;
+                } else if !self.help.popup.active && !self.focused().has_open_popup() {
+                    self.message_log.open();
+                    self.toast = None;
+                }
+            },
+            _ if self.message_log.popup.active => self.message_log.on_action(action),
+            Action::Accounts => {
+                if self.account_switcher.popup.active {
+                    self.account_switcher.popup.active = false;
+                } else if !self.help.popup.active && !self.focused().has_open_popup() {
+                    self.account_switcher.open(&self.accounts);
+                }
+            },
+            _ if self.account_switcher.popup.active => {
+                if self.account_switcher.on_action(action, &mut self.accounts) {
+                    self.switch_account();
+                }
+            },
+            Action::Help => {
+                if self.help.popup.active {
+                    self.help.popup.active = false;
+                } else if !self.focused().has_open_popup() {
+                    let title = format!("Help {}", self.focused().title());
+                    self.help.open(title);
+                }
+            },
+            Action::FocusBookmark => {
+                self.help.popup.active = false;
                 self.window_mode = WindowMode::Bookmark;
             },
-            KeyCode::F(6) => {
+            Action::FocusTab => {
+                self.help.popup.active = false;
                 self.window_mode = WindowMode::Tab;
             },
-            _ => {},
+            Action::Logout if !self.help.popup.active && !self.focused().has_open_popup() => {
+                self.logout();
+            },
+            Action::NextTab if self.window_mode == WindowMode::Tab
+                && !self.help.popup.active
+                && !self.focused().has_open_popup() => {
+                self.selected_tab = (self.selected_tab + 1) % self.tabs.len();
+            },
+            _ => {
+                if self.help.popup.active {
+                    self.help.on_action(action);
+                } else {
+                    self.focused().on_action(action);
+                }
+            },
         }
     }
 
-    fn active_pane(&mut self) {
+    fn switch_account(&mut self) {
+        self.tabs.iter_mut().for_each(|tab| tab.set_accounts(&self.accounts));
+
+        self.notifier.info(format!("Showing {}", self.accounts.label()));
+    }
+
+    fn logout(&self) {
+        let account = self.accounts.current().clone();
+        let notifier = self.notifier.clone();
+
+        thread::spawn(move || match account.tokens.logout() {
+            Ok(()) => notifier.info(format!("Logged out `{}`, restart to sign in again", account.name)),
+            Err(err) => notifier.error(format!("Failed to log out `{}`: {}", account.name, err)),
+        });
+    }
+
+    fn focused(&mut self) -> &mut dyn TabApp {
         match self.window_mode {
-            WindowMode::Bookmark => {
-                self.bookmarks.pane.active = true;
-                self.schedules.pane.active = false;
-                self.tasks.pane.active = false;
-                self.virtualbox.pane.active = false;
-            },
-            WindowMode::Tab => match self.selected_tab {
-                TabMode::Schedule => {
-                    self.bookmarks.pane.active = false;
-                    self.schedules.pane.active = true;
-                    self.tasks.pane.active = false;
-                    self.virtualbox.pane.active = false;
-                },
-                TabMode::Tasks => {
-                    self.bookmarks.pane.active = false;
-                    self.schedules.pane.active = false;
-                    self.tasks.pane.active = true;
-                    self.virtualbox.pane.active = false;
-                },
-                TabMode::VirtualBox => {
-                    self.bookmarks.pane.active = false;
-                    self.schedules.pane.active = false;
-                    self.tasks.pane.active = false;
-                    self.virtualbox.pane.active = true;
-                },
-            },
+            WindowMode::Bookmark => &mut self.bookmarks,
+            WindowMode::Tab => self.tabs[self.selected_tab].as_mut(),
+        }
+    }
+
+    fn active_pane(&mut self) {
+        self.bookmarks.pane().active = self.window_mode == WindowMode::Bookmark;
+
+        for (i, tab) in self.tabs.iter_mut().enumerate() {
+            tab.pane().active = self.window_mode == WindowMode::Tab && i == self.selected_tab;
         }
     }
 
@@ -257,109 +291,69 @@ impl<'a> App<'a> {
         ]);
         let [header_area, app_area] = tab_layout.areas(area);
 
-        let tabs = Tabs::new(self.tab_labels.clone())
-            .select(self.selected_tab.clone())
+        let tab_labels: Vec<Line> = self.tabs
+            .iter()
+            .map(|tab| Line::from(tab.title().to_string()))
+            .collect();
+
+        let tabs = Tabs::new(tab_labels)
+            .select(self.selected_tab)
             .style(Style::default().fg(Color::White))
             .highlight_style(Style::default().fg(Color::Green))
-            .block(Block::default().borders(Borders::ALL).title("  Tabs  "));
+            .block(Block::default().borders(Borders::ALL).title(format!("  Tabs [{}]  ", self.accounts.label())));
         frame.render_widget(tabs, header_area);
 
-        match self.selected_tab {
-            TabMode::Schedule => self.schedules.render(frame, app_area),
-            TabMode::Tasks => self.tasks.render(frame, app_area),
-            TabMode::VirtualBox => self.virtualbox.render(frame, app_area),
-        }
+        self.tabs[self.selected_tab].render(frame, app_area);
     }
 
     fn footer(&self, frame: &mut Frame, area: Rect) {
+        if let Some((notification, _)) = &self.toast {
+            let style = match notification.level {
+                Level::Info => Style::default().fg(Color::Green),
+                Level::Error => Style::default().fg(Color::Red),
+            };
+            let toast = Paragraph::new(Line::from(vec![
+                Span::styled(format!("  {}", notification.message), style),
+                Span::raw(format!("  (Messages: {})", self.keymap.describe(Context::List, Action::Messages))),
+            ]));
+            frame.render_widget(toast, area);
+            return;
+        }
+
         let footer_text = Paragraph::new(
             Line::from(vec![
-                Span::raw("  Quit: Esc, Open/Close Help APP: F1, Bookmark: F5, Tab APP: F6, Move Tab APP: Tab"),
+                Span::raw(format!(
+                    "  Quit: {}, Open/Close Help APP: {}, Bookmark: {}, Tab APP: {}, Move Tab APP: {}",
+                    self.keymap.describe(Context::List, Action::Quit),
+                    self.keymap.describe(Context::List, Action::Help),
+                    self.keymap.describe(Context::List, Action::FocusBookmark),
+                    self.keymap.describe(Context::List, Action::FocusTab),
+                    self.keymap.describe(Context::List, Action::NextTab),
+                )),
             ]),
         );
         frame.render_widget(footer_text, area);
     }
 
     fn popup(&mut self, frame: &mut Frame) {
-        self.bookmarks.help.render(
-            frame, 
-            vec![
-                "[ Select Bookmark ]",
-                "Open Bookmark           : Enter", 
-                "Focus Move Up           : Up, Right", 
-                "Focus Move Down         : Down, Left", 
-                "Execute Delete Schedule : Shift+D",
-                "Open/Close Add Bookmark : F2",
-                "Open/Close Edit Bookmark: F3",
-                "",
-                "[ Add Bookmark ]",
-                "Move Input Form     : Tab", 
-                "Execute Add Bookmark: F12", 
-                "",
-                "[ Edit Bookmark ]",
-                "Move Input Form      : Tab", 
-                "Execute Edit Bookmark: F12", 
-            ]
-        );
-        self.bookmarks.form.render(frame);
-
-        self.schedules.help.render(
-            frame,
-            vec![
-                "[ Select Schedule ]",
-                "Open Schedule           : Enter", 
-                "Focus Move Up           : Up, Right", 
-                "Focus Move Down         : Down, Left", 
-                "Execute Delete Schedule : Shift+D",
-                "Open/Close Add Schedule : F2",
-                "Open/Close Edit Schedule: F3",
-                "",
-                "[ Add Schedule ]",
-                "Move Input Form          : Tab",
-                "Execute Add Schedule     : F12",
-                "Start/End Datetime Format: yyyy/mm/ddThh:mm:ss",
-                "",
-                "[ Edit Schedule ]",
-                "Move Input Form          : Tab",
-                "Execute Edit Schedule    : F12",
-                "Start/End Datetime Format: yyyy/mm/ddThh:mm:ss+09:00",
-            ]
-        );
-        self.schedules.form.render(frame);
-
-        self.tasks.help.render(
-            frame,
-            vec![
-                "[ Select Task ]",
-                "Open Task            : Enter", 
-                "Focus Move Up        : Up, Right", 
-                "Focus Move Down      : Down, Left", 
-                "Execute Complete Task: Shift+C",
-                "Execute Delete Task  : Shift+D",
-                "Open/Close Add Task  : F2",
-                "Open/Close Edit Task : F3",
-                "",
-                "[ Add Task ]",
-                "Move Input Form          : Tab",
-                "Execute Add Task         : F12",
-                "Start/End Datetime Format: yyyy/mm/ddThh:mm:ss",
-                "",
-                "[ Edit Task ]",
-                "Move Input Form          : Tab",
-                "Execute Edit Task        : F12",
-                "Start/End Datetime Format: yyyy/mm/ddThh:mm:ss.000Z",
-            ]
-        );
-        self.tasks.form.render(frame);
-
-        self.virtualbox.help.render(
-            frame,
-            vec![
-                "[ Select VirtualBox ]",
-                "Open Machine   : Enter", 
-                "Focus Move Up  : Up, Right", 
-                "Focus Move Down: Down, Left", 
-            ]
-        );
+        let mut sections = self.focused().help();
+        sections.push(HelpSection::new("Global", Context::List, vec![
+            HelpItem::Action(Action::Quit, "Quit / Close Popup"),
+            HelpItem::Action(Action::Help, "Open/Close Help"),
+            HelpItem::Action(Action::Search, "Search Help"),
+            HelpItem::Action(Action::FocusBookmark, "Focus Bookmark"),
+            HelpItem::Action(Action::FocusTab, "Focus Tab APP"),
+            HelpItem::Action(Action::NextTab, "Move Tab APP"),
+            HelpItem::Action(Action::Refresh, "Refresh APP"),
+            HelpItem::Action(Action::Messages, "Open/Close Messages"),
+            HelpItem::Action(Action::Accounts, "Switch Google Account"),
+            HelpItem::Action(Action::Logout, "Logout Google Account"),
+        ]));
+        self.help.render(frame, &self.keymap, sections);
+
+        self.focused().render_popup(frame);
+
+        self.account_switcher.render(frame, &self.keymap, &self.accounts);
+        self.message_log.render(frame, &self.keymap, &self.messages);
     }
 }
@@ -1,23 +1,47 @@
-use std::{fs, process::Command};
+use std::{fs, io, path::PathBuf};
 
 use ratatui::{
-    crossterm::event::{KeyCode, KeyEvent}, 
+    crossterm::event::KeyEvent, 
     layout::Rect, 
     Frame
 };
 use serde::{Deserialize, Serialize};
 
 use crate::app::bookmark::form::{Form, Mode as FormMode};
+use crate::app::config::{Browser, Config};
+use crate::app::keymap::{Action, Context};
+use crate::app::notification::Notifier;
+use crate::app::tab::TabApp;
 use crate::app::ui::{
-    help::Help,
+    help::{HelpItem, HelpSection},
     select_list::SelectList,
     pane::Pane,
 };
 
-const JSON_PATH: &str = "bookmark.json";
-const BROWSER_PATH: &str = "/mnt/c/Program Files/BraveSoftware/Brave-Browser/Application/brave.exe";
 const APP_TITLE: &str = "Bookmark";
-const HELP_TITLE: &str = "Help Bookmark";
+
+#[derive(Debug, thiserror::Error)]
+pub enum BookmarkError {
+    #[error("Failed to read {}: {source}", path.display())]
+    Read {
+        path: PathBuf,
+        source: io::Error,
+    },
+    #[error("Failed to parse {}: {source}", path.display())]
+    Parse {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("Failed to write {}: {source}", path.display())]
+    Write {
+        path: PathBuf,
+        source: io::Error,
+    },
+    #[error("Failed to serialize bookmarks: {0}")]
+    Serialize(serde_json::Error),
+    #[error("Failed to open bookmark: {0}")]
+    Open(io::Error),
+}
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Bookmark {
@@ -27,7 +51,7 @@ pub struct Bookmark {
 
 impl Bookmark {
     fn value(&self) -> String {
-        format!("{}", self.title)
+        self.title.to_string()
     }
 }
 
@@ -37,47 +61,41 @@ pub struct Bookmarks<'a> {
     pub bookmarks: Vec<Bookmark>,
     pub list: SelectList<'a>,
     pub form: Form,
-    pub help: Help,
+    json_path: PathBuf,
+    browser: Browser,
+    notifier: Notifier,
 }
 
 impl<'a> Bookmarks<'a> {
-    pub fn new() -> Self {
+    pub fn new(config: &Config, notifier: &Notifier) -> Self {
         Self {
             pane: Pane::new(APP_TITLE),
-            bookmarks: Self::read(),
+            bookmarks: Vec::new(),
             list: SelectList::new(),
             form: Form::new(),
-            help: Help::new(HELP_TITLE)
-        }
-    }
-
-    fn read() -> Vec<Bookmark> {
-        let data = fs::read_to_string(JSON_PATH);
-
-        match data {
-            Ok(d) => serde_json::from_str(&d).unwrap_or_else(|_| Vec::new()),
-            Err(_) => Vec::new(),
+            json_path: config.files.bookmarks.clone(),
+            browser: config.browser.clone(),
+            notifier: notifier.clone(),
         }
     }
 
-    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let pane = self.pane.render(frame, area);
-
-        let list = self.bookmarks.iter().map(|bookmark| {
-            bookmark.value().clone()
-        })
-        .collect();
+    fn read(&self) -> Result<Vec<Bookmark>, BookmarkError> {
+        let data = match fs::read_to_string(&self.json_path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(source) => return Err(BookmarkError::Read { path: self.json_path.clone(), source }),
+        };
 
-        self.list.render(frame, pane, list);
+        serde_json::from_str(&data)
+            .map_err(|source| BookmarkError::Parse { path: self.json_path.clone(), source })
     }
 
     pub fn open(&self) {
         let bookmark = self.bookmarks[self.list.index].url.to_string();
 
-        Command::new(BROWSER_PATH)
-            .arg(bookmark)
-            .output()
-            .expect("Failed to execute open()");
+        if let Err(err) = self.browser.open(&bookmark).map_err(BookmarkError::Open) {
+            self.notifier.error(err.to_string());
+        }
     }
 
     pub fn add(&mut self) {
@@ -88,7 +106,7 @@ impl<'a> Bookmarks<'a> {
 
         self.bookmarks.push(bookmark);
 
-        self.output();
+        self.save("Added bookmark");
 
         self.form.popup.active = false;
         self.form.all_clear();
@@ -100,7 +118,7 @@ impl<'a> Bookmarks<'a> {
         bookmark.title = self.form.title.text.to_string();
         bookmark.url = self.form.url.text.to_string();
 
-        self.output();
+        self.save("Edited bookmark");
 
         self.form.popup.active = false;
         self.form.all_clear();
@@ -108,70 +126,143 @@ impl<'a> Bookmarks<'a> {
 
     pub fn delete(&mut self) {
         _ = self.bookmarks.remove(self.list.index);
+        self.list.clamp(self.bookmarks.len());
 
-        self.output();
+        self.save("Deleted bookmark");
     }
 
-    fn output(&self) {
+    fn save(&self, message: &str) {
+        match self.output() {
+            Ok(()) => self.notifier.info(message),
+            Err(err) => self.notifier.error(err.to_string()),
+        }
+    }
+
+    fn output(&self) -> Result<(), BookmarkError> {
         let json_string = serde_json::to_string_pretty(&self.bookmarks)
-            .expect("Failed to execute delete()");
+            .map_err(BookmarkError::Serialize)?;
 
-        fs::write(JSON_PATH, json_string).expect("Failed to execute output()");
+        fs::write(&self.json_path, json_string)
+            .map_err(|source| BookmarkError::Write { path: self.json_path.clone(), source })
     }
+}
 
-    pub fn key_binding(&mut self, key: KeyEvent) {
-        match key.code {
-            KeyCode::F(1) => {
-                if !self.help.popup.active & !self.form.popup.active {
-                    self.help.popup.active = true;
-                    self.form.popup.active = false;
-                }
-            }
-            KeyCode::F(2) => {
-                if !self.help.popup.active & !self.form.popup.active {
-                    self.form.popup.title = "Add Bookmark".to_string();
-                    self.form.mode = FormMode::New;
-                    self.help.popup.active = false;
-                    self.form.popup.active = true;
-                    self.form.all_clear();
-                    self.form.active_title();
-                }
-            }
-            KeyCode::F(3) => {
-                if !self.help.popup.active & !self.form.popup.active {
-                    self.form.popup.title = "Edit Bookmark".to_string();
-                    self.form.mode = FormMode::Edit;
-                    self.help.popup.active = false;
-                    self.form.popup.active = true;
-                    self.form.all_clear();
-                    self.form.active_title();
-
-                    let bookmark = &self.bookmarks[self.list.index];
-                    self.form.title.text = bookmark.title.to_string();
-                    self.form.url.text = bookmark.url.to_string();
-                }
-            }
-            KeyCode::F(12) => {
-                if self.form.popup.active {
-                    match self.form.mode {
-                        FormMode::New => self.add(),
-                        FormMode::Edit => self.edit(),
-                    }
-                }
-            }
-            KeyCode::Enter => {
-                if !self.help.popup.active & !self.form.popup.active {
-                    self.open();
-                }
+impl<'a> TabApp for Bookmarks<'a> {
+    fn title(&self) -> &str {
+        APP_TITLE
+    }
+
+    fn pane(&mut self) -> &mut Pane {
+        &mut self.pane
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let pane = self.pane.render(frame, area);
+
+        let list = self.bookmarks.iter().map(|bookmark| {
+            bookmark.value().clone()
+        })
+        .collect();
+
+        self.list.render(frame, pane, list);
+    }
+
+    fn render_popup(&mut self, frame: &mut Frame) {
+        self.form.render(frame);
+    }
+
+    fn on_action(&mut self, action: Action) {
+        match action {
+            Action::Add if !self.form.popup.active => {
+                self.form.popup.title = "Add Bookmark".to_string();
+                self.form.mode = FormMode::New;
+                self.form.popup.active = true;
+                self.form.all_clear();
+                self.form.active_title();
+            },
+            Action::Edit if !self.form.popup.active && !self.bookmarks.is_empty() => {
+                self.form.popup.title = "Edit Bookmark".to_string();
+                self.form.mode = FormMode::Edit;
+                self.form.popup.active = true;
+                self.form.all_clear();
+                self.form.active_title();
+
+                let bookmark = &self.bookmarks[self.list.index];
+                self.form.title.text = bookmark.title.to_string();
+                self.form.url.text = bookmark.url.to_string();
             },
-            KeyCode::Char('D') => self.delete(),
-            _ => {
-                if self.form.popup.active {
-                    self.form.key_binding(key);
-                } else if !self.help.popup.active {
-                    self.list.key_binding(key);
+            Action::Submit if self.form.popup.active => {
+                match self.form.mode {
+                    FormMode::New => self.add(),
+                    FormMode::Edit => self.edit(),
                 }
-            }
+            },
+            Action::NextField if self.form.popup.active => {
+                self.form.next();
+            },
+            Action::Open if !self.form.popup.active && !self.bookmarks.is_empty() => {
+                self.open();
+            },
+            Action::Delete if !self.form.popup.active && !self.bookmarks.is_empty() => {
+                self.delete();
+            },
+            Action::Refresh => self.refresh(),
+            Action::Up | Action::Left => self.list.up(),
+            Action::Down | Action::Right => self.list.down(),
+            Action::Top => self.list.top(),
+            Action::Bottom => self.list.bottom(),
+            _ => {},
         }
     }
-}
\ No newline at end of file
+
+    fn on_input(&mut self, key: KeyEvent) {
+        if self.form.popup.active {
+            self.form.key_binding(key);
+        }
+    }
+
+    fn help(&self) -> Vec<HelpSection> {
+        vec![
+            HelpSection::new("Select Bookmark", Context::List, vec![
+                HelpItem::Action(Action::Open, "Open Bookmark"),
+                HelpItem::Action(Action::Up, "Focus Move Up"),
+                HelpItem::Action(Action::Down, "Focus Move Down"),
+                HelpItem::Action(Action::Top, "Focus Move Top"),
+                HelpItem::Action(Action::Bottom, "Focus Move Bottom"),
+                HelpItem::Action(Action::Delete, "Execute Delete Bookmark"),
+                HelpItem::Action(Action::Add, "Open Add Bookmark"),
+                HelpItem::Action(Action::Edit, "Open Edit Bookmark"),
+            ]),
+            HelpSection::new("Add Bookmark", Context::Form, vec![
+                HelpItem::Action(Action::NextField, "Move Input Form"),
+                HelpItem::Action(Action::Submit, "Execute Add Bookmark"),
+                HelpItem::Action(Action::Quit, "Close Add Bookmark"),
+            ]),
+            HelpSection::new("Edit Bookmark", Context::Form, vec![
+                HelpItem::Action(Action::NextField, "Move Input Form"),
+                HelpItem::Action(Action::Submit, "Execute Edit Bookmark"),
+                HelpItem::Action(Action::Quit, "Close Edit Bookmark"),
+            ]),
+        ]
+    }
+
+    fn has_open_popup(&self) -> bool {
+        self.form.popup.active
+    }
+
+    fn close_popup(&mut self) {
+        self.form.popup.active = false;
+    }
+
+    fn refresh(&mut self) {
+        match self.read() {
+            Ok(bookmarks) => {
+                self.bookmarks = bookmarks;
+                self.list.clamp(self.bookmarks.len());
+            },
+            Err(err) => self.notifier.error(err.to_string()),
+        }
+    }
+
+    fn tick(&mut self) {}
+}
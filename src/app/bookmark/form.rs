@@ -88,6 +88,14 @@ impl Form {
         self.url.active = true;
     }
 
+    pub fn next(&mut self) {
+        if self.title.active {
+            self.active_url();
+        } else if self.url.active {
+            self.active_title();
+        }
+    }
+
     pub fn key_binding(&mut self, key: KeyEvent) {
         if self.title.active {
             self.title.key_binding(key);
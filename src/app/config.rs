@@ -0,0 +1,501 @@
+use std::{
+    collections::HashMap,
+    env,
+    fs,
+    io,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+use chrono_tz::Tz;
+use serde::{Deserialize, Deserializer};
+
+use crate::app::google::{calendar, tasks};
+use crate::app::keymap::{Action, KeySequence};
+
+const APP_NAME: &str = "rust-tui";
+const CONFIG_FILE: &str = "config.toml";
+const DEFAULT_ACCOUNT: &str = "default";
+const SCHEDULE_DAYS: [u32; 4] = [1, 3, 7, 30];
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("failed to read config file {}", path.display())]
+    Read {
+        path: PathBuf,
+        source: io::Error,
+    },
+    #[error("failed to parse config file {}", path.display())]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("invalid value for `{key}` in config file {}: {message}", path.display())]
+    Invalid {
+        path: PathBuf,
+        key: &'static str,
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TabKind {
+    Schedule,
+    Task,
+    VirtualBox,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Browser {
+    pub command: String,
+    pub args: Vec<String>,
+}
+
+impl Default for Browser {
+    fn default() -> Self {
+        Self {
+            command: "xdg-open".to_string(),
+            args: Vec::new(),
+        }
+    }
+}
+
+impl Browser {
+    pub fn open(&self, url: &str) -> io::Result<()> {
+        let output = Command::new(&self.command)
+            .args(&self.args)
+            .arg(url)
+            .output()?;
+
+        check_status(&self.command, &output)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct VirtualBox {
+    pub command: String,
+}
+
+impl Default for VirtualBox {
+    fn default() -> Self {
+        Self {
+            command: "VBoxManage".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Files {
+    pub bookmarks: PathBuf,
+    pub credentials: PathBuf,
+    pub token: PathBuf,
+    pub encrypt_token: bool,
+}
+
+impl Default for Files {
+    fn default() -> Self {
+        Self {
+            bookmarks: PathBuf::from("bookmark.json"),
+            credentials: PathBuf::from("credentials.json"),
+            token: PathBuf::from("token.json"),
+            encrypt_token: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Google {
+    pub calendar_url: String,
+    pub tasks_url: String,
+}
+
+impl Default for Google {
+    fn default() -> Self {
+        Self {
+            calendar_url: calendar::BASE_URL.to_string(),
+            tasks_url: tasks::BASE_URL.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AccountConfig {
+    pub name: String,
+    #[serde(default)]
+    pub token: Option<PathBuf>,
+}
+
+impl Default for AccountConfig {
+    fn default() -> Self {
+        Self {
+            name: DEFAULT_ACCOUNT.to_string(),
+            token: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScheduleConfig {
+    pub days: u32,
+}
+
+impl Default for ScheduleConfig {
+    fn default() -> Self {
+        Self {
+            days: 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Refresh {
+    pub schedule: u64,
+    pub task: u64,
+    pub virtualbox: u64,
+}
+
+impl Default for Refresh {
+    fn default() -> Self {
+        Self {
+            schedule: 300,
+            task: 300,
+            virtualbox: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Preset {
+    #[default]
+    Default,
+    Vim,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeymapConfig {
+    pub preset: Preset,
+    pub list: HashMap<Action, Vec<KeySequence>>,
+    pub form: HashMap<Action, Vec<KeySequence>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    #[serde(deserialize_with = "deserialize_time_zone")]
+    pub time_zone: Option<Tz>,
+    #[serde(deserialize_with = "deserialize_time_zone")]
+    pub secondary_time_zone: Option<Tz>,
+    pub tabs: Vec<TabKind>,
+    pub browser: Browser,
+    pub virtualbox: VirtualBox,
+    pub files: Files,
+    pub google: Google,
+    pub accounts: Vec<AccountConfig>,
+    pub merge_accounts: bool,
+    pub schedule: ScheduleConfig,
+    pub refresh: Refresh,
+    pub keymap: KeymapConfig,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            time_zone: None,
+            secondary_time_zone: None,
+            tabs: vec![TabKind::Schedule, TabKind::Task, TabKind::VirtualBox],
+            browser: Browser::default(),
+            virtualbox: VirtualBox::default(),
+            files: Files::default(),
+            google: Google::default(),
+            accounts: vec![AccountConfig::default()],
+            merge_accounts: false,
+            schedule: ScheduleConfig::default(),
+            refresh: Refresh::default(),
+            keymap: KeymapConfig::default(),
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> Result<Self, ConfigError> {
+        match config_dir() {
+            Some(dir) => Self::load_from(&dir.join(CONFIG_FILE)),
+            None => {
+                let mut config = Self::default();
+                config.resolve();
+                Ok(config)
+            },
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self, ConfigError> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(text) => {
+                let config: Self = toml::from_str(&text)
+                    .map_err(|source| ConfigError::Parse { path: path.to_path_buf(), source })?;
+                config.validate(path)?;
+                config
+            },
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(source) => return Err(ConfigError::Read { path: path.to_path_buf(), source }),
+        };
+
+        config.resolve();
+
+        Ok(config)
+    }
+
+    fn resolve(&mut self) {
+        self.files.resolve();
+
+        for account in &mut self.accounts {
+            let token = match account.token.take() {
+                Some(token) => Files::resolve_data_path(token),
+                None if account.name == DEFAULT_ACCOUNT => self.files.token.clone(),
+                None => {
+                    let stem = self.files.token.file_stem().unwrap_or_default().to_string_lossy();
+                    let name = match self.files.token.extension() {
+                        Some(extension) => format!("{}-{}.{}", stem, account.name, extension.to_string_lossy()),
+                        None => format!("{}-{}", stem, account.name),
+                    };
+                    self.files.token.with_file_name(name)
+                },
+            };
+            account.token = Some(token);
+        }
+    }
+
+    fn validate(&self, path: &Path) -> Result<(), ConfigError> {
+        let invalid = |key, message: &str| ConfigError::Invalid {
+            path: path.to_path_buf(),
+            key,
+            message: message.to_string(),
+        };
+
+        if self.tabs.is_empty() {
+            return Err(invalid("tabs", "at least one tab must be enabled"));
+        }
+        if self.tabs.iter().enumerate().any(|(i, tab)| self.tabs[..i].contains(tab)) {
+            return Err(invalid("tabs", "each tab may only be listed once"));
+        }
+        if self.browser.command.trim().is_empty() {
+            return Err(invalid("browser.command", "must not be empty"));
+        }
+        if self.virtualbox.command.trim().is_empty() {
+            return Err(invalid("virtualbox.command", "must not be empty"));
+        }
+        if reqwest::Url::parse(&self.google.calendar_url).is_err() {
+            return Err(invalid("google.calendar_url", "must be an absolute URL"));
+        }
+        if reqwest::Url::parse(&self.google.tasks_url).is_err() {
+            return Err(invalid("google.tasks_url", "must be an absolute URL"));
+        }
+        if self.accounts.is_empty() {
+            return Err(invalid("accounts", "at least one account must be configured"));
+        }
+        if self.accounts.iter().any(|account| account.name.trim().is_empty()) {
+            return Err(invalid("accounts.name", "must not be empty"));
+        }
+        if self.accounts.iter().enumerate().any(|(i, account)| {
+            self.accounts[..i].iter().any(|other| other.name == account.name)
+        }) {
+            return Err(invalid("accounts.name", "each account name may only be used once"));
+        }
+        if !SCHEDULE_DAYS.contains(&self.schedule.days) {
+            return Err(invalid("schedule.days", "must be 1, 3, 7 or 30"));
+        }
+
+        Ok(())
+    }
+}
+
+impl Files {
+    fn resolve(&mut self) {
+        self.bookmarks = Self::expand_home(self.bookmarks.clone());
+        self.credentials = Self::resolve_data_path(self.credentials.clone());
+        self.token = Self::resolve_data_path(self.token.clone());
+    }
+
+    fn expand_home(path: PathBuf) -> PathBuf {
+        match (path.strip_prefix("~"), env::var_os("HOME")) {
+            (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+            _ => path,
+        }
+    }
+
+    fn resolve_data_path(path: PathBuf) -> PathBuf {
+        let path = Self::expand_home(path);
+
+        match data_dir() {
+            Some(dir) if path.is_relative() => dir.join(path),
+            _ => path,
+        }
+    }
+}
+
+fn deserialize_time_zone<'de, D>(deserializer: D) -> Result<Option<Tz>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let name = String::deserialize(deserializer)?;
+
+    name.parse::<Tz>().map(Some).map_err(|_| {
+        serde::de::Error::custom(format!("unknown time zone `{}`, expected an IANA name such as `Asia/Tokyo`", name))
+    })
+}
+
+pub fn check_status(command: &str, output: &Output) -> io::Result<()> {
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let message = match stderr.trim() {
+        "" => format!("`{}` exited with {}", command, output.status),
+        stderr => format!("`{}` exited with {}: {}", command, output.status, stderr),
+    };
+
+    Err(io::Error::other(message))
+}
+
+fn xdg_dir(var: &str, fallback: &str) -> Option<PathBuf> {
+    let dir = env::var_os(var)
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(fallback)))?;
+
+    Some(dir.join(APP_NAME))
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    xdg_dir("XDG_CONFIG_HOME", ".config")
+}
+
+pub fn data_dir() -> Option<PathBuf> {
+    xdg_dir("XDG_DATA_HOME", ".local/share")
+}
+
+pub fn system_time_zone() -> Tz {
+    iana_time_zone::get_timezone()
+        .ok()
+        .and_then(|name| name.parse().ok())
+        .unwrap_or(Tz::UTC)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+    fn load(text: &str) -> Result<Config, ConfigError> {
+        let id = NEXT_ID.fetch_add(1, Ordering::SeqCst);
+        let path = env::temp_dir().join(format!("rust-tui-config-{}-{}.toml", std::process::id(), id));
+        fs::write(&path, text).unwrap();
+
+        let config = Config::load_from(&path);
+        let _ = fs::remove_file(&path);
+        config
+    }
+
+    fn invalid_key(text: &str) -> Option<&'static str> {
+        match load(text) {
+            Err(ConfigError::Invalid { key, .. }) => Some(key),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn uses_defaults_without_a_file() {
+        let path = env::temp_dir().join("rust-tui-config-missing.toml");
+        let config = Config::load_from(&path).unwrap();
+
+        assert_eq!(config.tabs, [TabKind::Schedule, TabKind::Task, TabKind::VirtualBox]);
+        assert_eq!(config.schedule.days, 3);
+        assert_eq!(config.accounts[0].name, DEFAULT_ACCOUNT);
+    }
+
+    #[test]
+    fn parses_settings() {
+        let config = load(r#"
+            time_zone = "Europe/Berlin"
+            tabs = ["task", "schedule"]
+
+            [browser]
+            command = "firefox"
+            args = ["--new-tab"]
+
+            [schedule]
+            days = 7
+
+            [keymap]
+            preset = "vim"
+
+            [keymap.list]
+            quit = ["ctrl+c"]
+        "#).unwrap();
+
+        assert_eq!(config.time_zone, Some(chrono_tz::Europe::Berlin));
+        assert_eq!(config.tabs, [TabKind::Task, TabKind::Schedule]);
+        assert_eq!(config.browser.args, ["--new-tab"]);
+        assert_eq!(config.schedule.days, 7);
+        assert!(matches!(config.keymap.preset, Preset::Vim));
+        assert_eq!(config.keymap.list[&Action::Quit][0].to_string(), "Ctrl+c");
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_time_zones() {
+        assert!(matches!(load("colour = \"red\""), Err(ConfigError::Parse { .. })));
+        assert!(matches!(load("time_zone = \"Mars/Olympus\""), Err(ConfigError::Parse { .. })));
+    }
+
+    #[test]
+    fn validates_values() {
+        assert_eq!(invalid_key("tabs = []"), Some("tabs"));
+        assert_eq!(invalid_key("tabs = [\"task\", \"task\"]"), Some("tabs"));
+        assert_eq!(invalid_key("[browser]\ncommand = \" \""), Some("browser.command"));
+        assert_eq!(invalid_key("[virtualbox]\ncommand = \"\""), Some("virtualbox.command"));
+        assert_eq!(invalid_key("[google]\ncalendar_url = \"calendar\""), Some("google.calendar_url"));
+        assert_eq!(invalid_key("accounts = []"), Some("accounts"));
+        assert_eq!(invalid_key("[[accounts]]\nname = \"a\"\n[[accounts]]\nname = \"a\""), Some("accounts.name"));
+        assert_eq!(invalid_key("[schedule]\ndays = 5"), Some("schedule.days"));
+        assert_eq!(invalid_key("[schedule]\ndays = 30"), None);
+    }
+
+    #[test]
+    fn resolves_account_token_paths() {
+        let config = load(r#"
+            [files]
+            token = "/secrets/token.json"
+
+            [[accounts]]
+            name = "default"
+
+            [[accounts]]
+            name = "work"
+
+            [[accounts]]
+            name = "home"
+            token = "/secrets/home.json"
+        "#).unwrap();
+
+        let tokens: Vec<PathBuf> = config.accounts.iter().filter_map(|account| account.token.clone()).collect();
+        assert_eq!(tokens, [
+            PathBuf::from("/secrets/token.json"),
+            PathBuf::from("/secrets/token-work.json"),
+            PathBuf::from("/secrets/home.json"),
+        ]);
+    }
+}
@@ -0,0 +1,234 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Weekday};
+
+const DATE_FORMATS: [&str; 2] = ["%Y-%m-%d", "%Y/%m/%d"];
+const DATE_TIME_FORMATS: [&str; 4] = ["%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S", "%Y/%m/%dT%H:%M", "%Y/%m/%dT%H:%M:%S"];
+const TIME_FORMATS: [&str; 2] = ["%H:%M", "%H:%M:%S"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum When {
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+}
+
+impl When {
+    pub fn date(&self) -> NaiveDate {
+        match self {
+            Self::Date(date) => *date,
+            Self::DateTime(date_time) => date_time.date(),
+        }
+    }
+
+    pub fn date_time(&self) -> NaiveDateTime {
+        match self {
+            Self::Date(date) => date.and_time(NaiveTime::MIN),
+            Self::DateTime(date_time) => *date_time,
+        }
+    }
+}
+
+pub fn parse<Tz: TimeZone>(text: &str, now: &DateTime<Tz>) -> Option<When> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(text) {
+        return Some(When::DateTime(date_time.with_timezone(&now.timezone()).naive_local()));
+    }
+
+    if let Some(date_time) = DATE_TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+    {
+        return Some(When::DateTime(date_time));
+    }
+
+    let text = text.to_lowercase();
+
+    let now = now.naive_local().with_second(0)?.with_nanosecond(0)?;
+    if text == "now" {
+        return Some(When::DateTime(now));
+    }
+    if let Some(duration) = text.strip_prefix('+').and_then(parse_duration) {
+        return now.checked_add_signed(duration).map(When::DateTime);
+    }
+    if let Some(duration) = text.strip_prefix('-').and_then(parse_duration) {
+        return now.checked_sub_signed(duration).map(When::DateTime);
+    }
+
+    let today = now.date();
+    let mut date = None;
+    let mut time = None;
+    let mut words = text.split_whitespace().filter(|word| *word != "at");
+
+    while let Some(word) = words.next() {
+        if word == "next" {
+            let weekday = words.next()?.parse::<Weekday>().ok()?;
+            if date.replace(next_weekday(today, weekday, true)).is_some() {
+                return None;
+            }
+        } else if let Some(parsed) = parse_date(word, today) {
+            if date.replace(parsed).is_some() {
+                return None;
+            }
+        } else if let Some(parsed) = parse_time(word) {
+            if time.replace(parsed).is_some() {
+                return None;
+            }
+        } else {
+            return None;
+        }
+    }
+
+    match (date, time) {
+        (Some(date), None) => Some(When::Date(date)),
+        (date, Some(time)) => Some(When::DateTime(date.unwrap_or(today).and_time(time))),
+        (None, None) => None,
+    }
+}
+
+pub fn parse_duration(text: &str) -> Option<Duration> {
+    let text = text.trim().trim_start_matches('+').to_lowercase();
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    let mut units = 0;
+
+    for char in text.chars().filter(|char| !char.is_whitespace()) {
+        if char.is_ascii_digit() {
+            number.push(char);
+            continue;
+        }
+
+        let value: i64 = number.parse().ok()?;
+        let duration = match char {
+            'w' => Duration::try_weeks(value)?,
+            'd' => Duration::try_days(value)?,
+            'h' => Duration::try_hours(value)?,
+            'm' => Duration::try_minutes(value)?,
+            _ => return None,
+        };
+        total = total.checked_add(&duration)?;
+        number.clear();
+        units += 1;
+    }
+
+    (units > 0 && number.is_empty()).then_some(total)
+}
+
+fn parse_date(word: &str, today: NaiveDate) -> Option<NaiveDate> {
+    match word {
+        "today" => return Some(today),
+        "tomorrow" | "tmr" => return today.succ_opt(),
+        "yesterday" => return today.pred_opt(),
+        _ => {},
+    }
+
+    if let Ok(weekday) = word.parse::<Weekday>() {
+        return Some(next_weekday(today, weekday, false));
+    }
+
+    DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(word, format).ok())
+        .or_else(|| NaiveDate::parse_from_str(&format!("{}/{}", today.year(), word), "%Y/%m/%d").ok())
+}
+
+fn parse_time(word: &str) -> Option<NaiveTime> {
+    match word {
+        "noon" => return NaiveTime::from_hms_opt(12, 0, 0),
+        "midnight" => return Some(NaiveTime::MIN),
+        _ => {},
+    }
+
+    if let Some(time) = TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(word, format).ok())
+    {
+        return Some(time);
+    }
+
+    let (clock, offset) = match (word.strip_suffix("am"), word.strip_suffix("pm")) {
+        (Some(clock), _) => (clock, 0),
+        (_, Some(clock)) => (clock, 12),
+        _ => return None,
+    };
+    let (hour, minute) = clock.split_once(':').unwrap_or((clock, "0"));
+    let hour: u32 = hour.parse().ok().filter(|hour| (1..=12).contains(hour))?;
+
+    NaiveTime::from_hms_opt(hour % 12 + offset, minute.parse().ok()?, 0)
+}
+
+fn next_weekday(today: NaiveDate, weekday: Weekday, after_today: bool) -> NaiveDate {
+    let days = (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+    let days = if days == 0 && after_today { 7 } else { days };
+
+    today + Duration::days(i64::from(days))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, Utc};
+
+    use super::*;
+
+    fn now() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2024-12-04T09:41:30+09:00").unwrap()
+    }
+
+    fn at(text: &str) -> When {
+        When::DateTime(NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M").unwrap())
+    }
+
+    fn on(text: &str) -> When {
+        When::Date(NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap())
+    }
+
+    #[test]
+    fn parses_relative_days_and_times() {
+        assert_eq!(parse("tomorrow 3pm", &now()), Some(at("2024-12-05 15:00")));
+        assert_eq!(parse("Today at 12:30am", &now()), Some(at("2024-12-04 00:30")));
+        assert_eq!(parse("fri", &now()), Some(on("2024-12-06")));
+        assert_eq!(parse("wed", &now()), Some(on("2024-12-04")));
+        assert_eq!(parse("next wed 10:00", &now()), Some(at("2024-12-11 10:00")));
+        assert_eq!(parse("noon", &now()), Some(at("2024-12-04 12:00")));
+        assert_eq!(parse("+2h", &now()), Some(at("2024-12-04 11:41")));
+        assert_eq!(parse("-1d", &now()), Some(at("2024-12-03 09:41")));
+    }
+
+    #[test]
+    fn parses_iso_and_local_formats() {
+        assert_eq!(parse("2024-12-01 10:00", &now()), Some(at("2024-12-01 10:00")));
+        assert_eq!(parse("2024/12/01T10:00:00", &now()), Some(at("2024-12-01 10:00")));
+        assert_eq!(parse("12/25", &now()), Some(on("2024-12-25")));
+        assert_eq!(parse("2024-12-01T01:00:00Z", &now()), Some(at("2024-12-01 10:00")));
+        assert_eq!(
+            parse("2024-12-01T01:00:00Z", &now().with_timezone(&Utc)),
+            Some(at("2024-12-01 01:00")),
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_input() {
+        for text in ["", "soon", "tomorrow tomorrow", "13pm", "next", "3pm 4pm", "2024-13-01"] {
+            assert_eq!(parse(text, &now()), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("1h30m"), Some(Duration::minutes(90)));
+        assert_eq!(parse_duration("+2d 4h"), Some(Duration::hours(52)));
+        assert_eq!(parse_duration("1w"), Some(Duration::weeks(1)));
+        assert_eq!(parse_duration("90"), None);
+        assert_eq!(parse_duration("1h30"), None);
+        assert_eq!(parse_duration("10:00"), None);
+    }
+
+    #[test]
+    fn rejects_out_of_range_durations() {
+        assert_eq!(parse_duration("9999999999999w"), None);
+        assert_eq!(parse_duration("100000000000d 100000000000d"), None);
+        assert_eq!(parse("+99999999w", &now()), None);
+        assert_eq!(parse("-99999999w", &now()), None);
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use crate::app::config::Google;
+use crate::app::google::{
+    calendar::{CalendarApi, HttpCalendar},
+    tasks::{HttpTasks, TasksApi},
+    token::TokenProvider,
+};
+
+#[derive(Debug, Clone)]
+pub struct Account {
+    pub name: String,
+    pub tokens: TokenProvider,
+    pub calendar: Arc<dyn CalendarApi>,
+    pub tasks: Arc<dyn TasksApi>,
+}
+
+impl Account {
+    pub fn new(name: &str, tokens: TokenProvider, google: &Google) -> Self {
+        Self {
+            name: name.to_string(),
+            calendar: Arc::new(HttpCalendar::new(&google.calendar_url, tokens.clone())),
+            tasks: Arc::new(HttpTasks::new(&google.tasks_url, tokens.clone())),
+            tokens,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Accounts {
+    pub list: Vec<Account>,
+    pub current: usize,
+    pub merged: bool,
+}
+
+impl Accounts {
+    pub fn new(list: Vec<Account>, merged: bool) -> Self {
+        Self {
+            list,
+            current: 0,
+            merged,
+        }
+    }
+
+    pub fn current(&self) -> &Account {
+        &self.list[self.current]
+    }
+
+    pub fn get(&self, name: &str) -> &Account {
+        self.list
+            .iter()
+            .find(|account| account.name == name)
+            .unwrap_or_else(|| self.current())
+    }
+
+    pub fn selected(&self) -> Vec<Account> {
+        if self.merged {
+            self.list.clone()
+        } else {
+            vec![self.current().clone()]
+        }
+    }
+
+    pub fn is_merged(&self) -> bool {
+        self.merged && self.list.len() > 1
+    }
+
+    pub fn label(&self) -> &str {
+        if self.is_merged() {
+            "all accounts"
+        } else {
+            &self.current().name
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use reqwest::{
+    blocking::{RequestBuilder, Response},
+    StatusCode,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::app::google::{
+    authentication::AuthError,
+    token::TokenProvider,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApiError {
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("authorization failed: {0}")]
+    Auth(#[from] AuthError),
+    #[error("HTTP {status}: {message}")]
+    Status {
+        status: StatusCode,
+        message: String,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Page<T> {
+    #[serde(default = "Vec::new")]
+    items: Vec<T>,
+    next_page_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: ErrorDetail,
+}
+
+#[derive(Deserialize)]
+struct ErrorDetail {
+    message: String,
+}
+
+pub fn segment(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'@' => char::from(byte).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+pub fn check(response: Response) -> Result<Response, ApiError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let body = response.text().unwrap_or_default();
+    let message = match serde_json::from_str::<ErrorBody>(&body) {
+        Ok(body) => body.error.message,
+        Err(_) if body.trim().is_empty() => status.canonical_reason().unwrap_or("Unknown error").to_string(),
+        Err(_) => body.trim().chars().take(200).collect(),
+    };
+
+    Err(ApiError::Status { status, message })
+}
+
+pub fn send<F>(tokens: &TokenProvider, request: F) -> Result<Response, ApiError>
+where
+    F: Fn() -> RequestBuilder,
+{
+    let access_token = tokens.access_token()?;
+    let response = request().bearer_auth(&access_token).send()?;
+
+    if response.status() == StatusCode::UNAUTHORIZED {
+        let access_token = tokens.reject(&access_token)?;
+        return check(request().bearer_auth(&access_token).send()?);
+    }
+
+    check(response)
+}
+
+pub fn list<T, F>(tokens: &TokenProvider, request: F) -> Result<Vec<T>, ApiError>
+where
+    T: DeserializeOwned,
+    F: Fn() -> RequestBuilder,
+{
+    let mut items = Vec::new();
+    let mut page_token: Option<String> = None;
+
+    loop {
+        let page: Page<T> = send(tokens, || match &page_token {
+            Some(page_token) => request().query(&[("pageToken", page_token)]),
+            None => request(),
+        })?
+        .json()?;
+
+        items.extend(page.items);
+
+        match page.next_page_token {
+            Some(next) => page_token = Some(next),
+            None => return Ok(items),
+        }
+    }
+}
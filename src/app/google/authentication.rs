@@ -1,23 +1,114 @@
 use std::fs;
-use std::io::{self, Read, Write};
+use std::io;
+use std::path::{Path, PathBuf};
 
 use oauth2::{
     AuthUrl, 
     ClientId, 
     ClientSecret, 
-    TokenResponse, 
-    AuthorizationCode,
-    reqwest::http_client,
-    RefreshToken,
-    basic::BasicClient,
-    RedirectUrl, 
+    basic::{BasicClient, BasicErrorResponseType},
+    RequestTokenError,
+    RevocationUrl,
     TokenUrl,
 };
-use serde::{
-    Serialize,
-    Deserialize,
+use serde::Deserialize;
+
+use crate::app::config::{Browser, Config};
+use crate::app::google::{
+    loopback,
+    account::{Account, Accounts},
+    storage::{self, TokenStore},
+    token::{TokenInfo, TokenProvider},
 };
 
+type TokenError = oauth2::basic::BasicRequestTokenError<oauth2::reqwest::HttpClientError>;
+type RevocationError = oauth2::RequestTokenError<
+    oauth2::reqwest::HttpClientError,
+    oauth2::basic::BasicRevocationErrorResponse,
+>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    #[error("failed to read credentials file {}", path.display())]
+    ReadCredentials {
+        path: PathBuf,
+        source: io::Error,
+    },
+    #[error("failed to parse credentials file {}", path.display())]
+    ParseCredentials {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("invalid {name} in credentials file")]
+    InvalidUrl {
+        name: &'static str,
+        source: oauth2::url::ParseError,
+    },
+    #[error("failed to exchange authorization code")]
+    Exchange(#[source] TokenError),
+    #[error("failed to refresh access token")]
+    Refresh(#[source] TokenError),
+    #[error("token response did not include a refresh token")]
+    MissingRefreshToken,
+    #[error("failed to read token file {}", path.display())]
+    ReadToken {
+        path: PathBuf,
+        source: io::Error,
+    },
+    #[error("failed to parse token file {}", path.display())]
+    ParseToken {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("failed to save token file {}", path.display())]
+    SaveToken {
+        path: PathBuf,
+        source: io::Error,
+    },
+    #[error("failed to delete token file {}", path.display())]
+    DeleteToken {
+        path: PathBuf,
+        source: io::Error,
+    },
+    #[error("failed to read the token passphrase")]
+    Passphrase(#[source] io::Error),
+    #[error("failed to decrypt token file {}, check the passphrase", path.display())]
+    Decrypt {
+        path: PathBuf,
+    },
+    #[error("token encryption failed: {0}")]
+    Cipher(String),
+    #[error("token revocation is not configured")]
+    RevocationUrl(#[source] oauth2::ConfigurationError),
+    #[error("failed to revoke token")]
+    Revoke(#[source] RevocationError),
+    #[error("signed out, restart to sign in again")]
+    SignedOut,
+    #[error("failed to sign in account `{name}`")]
+    Account {
+        name: String,
+        source: Box<AuthError>,
+    },
+    #[error("failed to listen for the authorization redirect")]
+    Listen(#[source] io::Error),
+    #[error("failed to receive the authorization redirect")]
+    Callback(#[source] io::Error),
+    #[error("authorization redirect has a mismatched state parameter")]
+    CsrfMismatch,
+    #[error("authorization was denied: {0}")]
+    Denied(String),
+}
+
+impl AuthError {
+    pub fn is_invalid_grant(&self) -> bool {
+        matches!(
+            self,
+            Self::Refresh(RequestTokenError::ServerResponse(response))
+                if *response.error() == BasicErrorResponseType::InvalidGrant
+        )
+    }
+}
+
 #[derive(Deserialize)]
 pub struct Credentials {
     pub installed: Installed,
@@ -29,127 +120,102 @@ pub struct Installed {
     pub client_secret: String,
     pub auth_uri: String,
     pub token_uri: String,
-    pub redirect_uris: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct TokenInfo {
-    pub access_token: String,
-    pub refresh_token: String,
 }
 
 pub struct Authentication {
-    pub token_info: TokenInfo
+    pub accounts: Accounts,
 }
 
+const REVOCATION_URL: &str = "https://oauth2.googleapis.com/revoke";
+const SCOPES: [&str; 2] = [
+    "https://www.googleapis.com/auth/calendar",
+    "https://www.googleapis.com/auth/tasks",
+];
+
 impl Authentication {
-    pub fn new() -> Self {
-        Self {
-            token_info: Self::read_token(),
-        }
+    pub fn new(config: &Config) -> Result<Self, AuthError> {
+        Ok(Self {
+            accounts: Self::read_accounts(config)?,
+        })
     }
 
-    fn read_token() -> TokenInfo {
-        let credentials: Credentials = Self::read_credentials().unwrap();
+    fn read_accounts(config: &Config) -> Result<Accounts, AuthError> {
+        let credentials = Self::read_credentials(&config.files.credentials)?;
+        let installed = credentials.installed;
 
-        let auth_url = match AuthUrl::new(credentials.installed.auth_uri.clone()) {
-            Ok(url) => url,
-            Err(err) => panic!("Failed to parse AuthUrl: {:?}", err),
-        };
-        let token_url = match TokenUrl::new(credentials.installed.token_uri.clone()) {
-            Ok(url) => url,
-            Err(err) => panic!("Failed to parse TokenUrl: {:?}", err),
-        };
-        let redirect_url = match RedirectUrl::new(credentials.installed.redirect_uris[0].clone()) {
-            Ok(url) => url,
-            Err(err) => panic!("Failed to parse RedirectUrl: {:?}", err),
-        };
+        let auth_url = AuthUrl::new(installed.auth_uri)
+            .map_err(|source| AuthError::InvalidUrl { name: "auth_uri", source })?;
+        let token_url = TokenUrl::new(installed.token_uri)
+            .map_err(|source| AuthError::InvalidUrl { name: "token_uri", source })?;
+        let revocation_url = RevocationUrl::new(REVOCATION_URL.to_string())
+            .map_err(|source| AuthError::InvalidUrl { name: "revocation_uri", source })?;
 
         let client = BasicClient::new(
-            ClientId::new(credentials.installed.client_id.clone()),
-            Some(ClientSecret::new(credentials.installed.client_secret.clone())),
+            ClientId::new(installed.client_id),
+            Some(ClientSecret::new(installed.client_secret)),
             auth_url,
             Some(token_url),
         )
-        .set_redirect_uri(redirect_url);
-
-        match Self::get_token(&client) {
-            Ok(token) => token,
-            Err(err) => panic!("Failed to retrieve token: {:?}", err),
-        }
-    }
+        .set_revocation_uri(revocation_url);
 
-    pub fn read_credentials() -> Result<Credentials, Box<dyn std::error::Error>> {
-        let mut file = fs::File::open("credentials.json")?;
-        let mut credentials_str = String::new();
-        file.read_to_string(&mut credentials_str)?;
-
-        let credentials: Credentials = serde_json::from_str(&credentials_str)?;
-        Ok(credentials)
-    }
-
-    pub fn get_token(client: &BasicClient) -> Result<TokenInfo, Box<dyn std::error::Error>> {
-        let mut token_info = if let Some(token) = Self::load_token() {
-            token
+        let passphrase = if config.files.encrypt_token {
+            Some(storage::passphrase()?)
         } else {
-            let auth_code = Self::get_authentication_code(&client)?;
+            None
+        };
 
-            let token = 
-                client.exchange_code(AuthorizationCode::new(auth_code))
-                .request(http_client)?;
+        let accounts = config.accounts.iter().map(|account| {
+            let path = account.token.as_deref().unwrap_or(&config.files.token);
+            let store = TokenStore::new(path, passphrase.clone());
 
-            let token_info = TokenInfo {
-                access_token: token.access_token().secret().clone(),
-                refresh_token: token.refresh_token().unwrap().secret().clone(),
-            };
+            let tokens = Self::get_token(&client, store, &account.name, &config.browser)
+                .map_err(|source| AuthError::Account { name: account.name.clone(), source: Box::new(source) })?;
 
-            Self::save_token(&token_info)?;
-            token_info
-        };
+            Ok(Account::new(&account.name, tokens, &config.google))
+        })
+        .collect::<Result<Vec<_>, AuthError>>()?;
 
-        let refresh_token = RefreshToken::new(token_info.refresh_token.clone());
-        let token_result = client
-            .exchange_refresh_token(&refresh_token)
-            .request(oauth2::reqwest::http_client)?;
+        Ok(Accounts::new(accounts, config.merge_accounts))
+    }
 
-        token_info.access_token = token_result.access_token().secret().clone();
-        if let Some(new_refresh_token) = token_result.refresh_token() {
-            token_info.refresh_token = new_refresh_token.secret().clone();
-        }
-        Self::save_token(&token_info)?;
+    pub fn read_credentials(path: &Path) -> Result<Credentials, AuthError> {
+        storage::restrict(path)
+            .map_err(|source| AuthError::ReadCredentials { path: path.to_path_buf(), source })?;
+        let credentials_str = fs::read_to_string(path)
+            .map_err(|source| AuthError::ReadCredentials { path: path.to_path_buf(), source })?;
 
-        Ok(token_info)
+        serde_json::from_str(&credentials_str)
+            .map_err(|source| AuthError::ParseCredentials { path: path.to_path_buf(), source })
     }
 
-    fn save_token(token_info: &TokenInfo) -> std::io::Result<()> {
-        let token_json = serde_json::to_string(token_info)?;
-        fs::write("token.json", token_json)?;
-        Ok(())
-    }
+    pub fn get_token(client: &BasicClient, mut store: TokenStore, name: &str, browser: &Browser) -> Result<TokenProvider, AuthError> {
+        let token_info = match store.load()? {
+            Some(token_info) => token_info,
+            None => {
+                let token_info = Self::sign_in(client, name, browser)?;
+                store.save(&token_info)?;
+                token_info
+            },
+        };
 
-    fn load_token() -> Option<TokenInfo> {
-        if let Ok(token_json) = fs::read_to_string("token.json") {
-            serde_json::from_str(&token_json).ok()
-        } else {
-            None
+        let tokens = TokenProvider::new(client.clone(), store, token_info);
+        match tokens.access_token() {
+            Err(err) if err.is_invalid_grant() => {
+                println!("The saved sign-in for `{}` has expired or been revoked.", name);
+                tokens.replace(Self::sign_in(client, name, browser)?)?;
+            },
+            result => {
+                result?;
+            },
         }
-    }
-
-    fn get_authentication_code(client: &BasicClient) -> Result<String, Box<dyn std::error::Error>> {
-        let (auth_url, _) = client
-            .authorize_url(|| oauth2::CsrfToken::new_random())
-            .add_scope(oauth2::Scope::new("https://www.googleapis.com/auth/calendar".to_string()))
-            .add_scope(oauth2::Scope::new("https://www.googleapis.com/auth/tasks".to_string()))
-            .url();
 
-        println!("Go to the following URL and authorize the application: {}", auth_url);
+        Ok(tokens)
+    }
 
-        print!("Enter the authorization code: ");
-        io::stdout().flush()?;
-        let mut auth_code = String::new();
-        io::stdin().read_line(&mut auth_code)?;
-        let auth_code = auth_code.trim().to_string();
+    fn sign_in(client: &BasicClient, name: &str, browser: &Browser) -> Result<TokenInfo, AuthError> {
+        println!("Sign in to the Google account for `{}`.", name);
+        let token = loopback::authorize(client.clone(), &SCOPES, |url| browser.open(url))?;
 
-        Ok(auth_code)
+        TokenInfo::from_response(&token)
     }
-}
\ No newline at end of file
+}
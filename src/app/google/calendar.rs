@@ -0,0 +1,706 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use chrono::{DateTime, FixedOffset, NaiveDate, SecondsFormat, TimeZone, Utc};
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::app::google::{
+    api::{self, ApiError},
+    token::TokenProvider,
+};
+
+pub const BASE_URL: &str = "https://www.googleapis.com/calendar/v3";
+pub const PRIMARY: &str = "primary";
+pub const EVENT_COLORS: [(&str, &str); 11] = [
+    ("Lavender", "#7986cb"),
+    ("Sage", "#33b679"),
+    ("Grape", "#8e24aa"),
+    ("Flamingo", "#e67c73"),
+    ("Banana", "#f6bf26"),
+    ("Tangerine", "#f4511e"),
+    ("Peacock", "#039be5"),
+    ("Graphite", "#616161"),
+    ("Blueberry", "#3f51b5"),
+    ("Basil", "#0b8043"),
+    ("Tomato", "#d50000"),
+];
+pub const MEET: &str = "hangoutsMeet";
+const CONFERENCE_DATA_VERSION: (&str, &str) = ("conferenceDataVersion", "1");
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Calendar {
+    #[serde(default)]
+    pub id: String,
+    pub summary: Option<String>,
+    pub time_zone: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarListEntry {
+    pub id: String,
+    #[serde(default)]
+    pub summary: String,
+    pub summary_override: Option<String>,
+    pub background_color: Option<String>,
+    pub foreground_color: Option<String>,
+    pub time_zone: Option<String>,
+    #[serde(default)]
+    pub access_role: String,
+    #[serde(default)]
+    pub primary: bool,
+    #[serde(default)]
+    pub selected: bool,
+}
+
+impl CalendarListEntry {
+    pub fn name(&self) -> &str {
+        self.summary_override.as_deref().unwrap_or(&self.summary)
+    }
+
+    pub fn is_writable(&self) -> bool {
+        matches!(self.access_role.as_str(), "owner" | "writer")
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Event {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hangout_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conference_data: Option<ConferenceData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organizer: Option<Person>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attendees: Vec<Attendee>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reminders: Option<Reminders>,
+    #[serde(default)]
+    pub start: EventTime,
+    #[serde(default)]
+    pub end: EventTime,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recurrence: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring_event_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_start_time: Option<EventTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventTime {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_time: Option<DateTime<FixedOffset>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+}
+
+impl EventTime {
+    pub fn at<Tz: TimeZone>(date_time: &DateTime<Tz>, time_zone: &str) -> Self {
+        Self {
+            date: None,
+            date_time: Some(date_time.fixed_offset()),
+            time_zone: Some(time_zone.to_string()),
+        }
+    }
+
+    pub fn in_zone<Tz: TimeZone>(&self, time_zone: &Tz) -> Option<DateTime<Tz>> {
+        match (self.date_time, self.date) {
+            (Some(date_time), _) => Some(date_time.with_timezone(time_zone)),
+            (None, Some(date)) => time_zone
+                .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+                .earliest(),
+            (None, None) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(rename = "self", default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_self: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attendee {
+    pub email: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub organizer: bool,
+    #[serde(rename = "self", default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_self: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConferenceData {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entry_points: Vec<EntryPoint>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conference_solution: Option<ConferenceSolution>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_request: Option<CreateConferenceRequest>,
+}
+
+impl ConferenceData {
+    pub fn video_uri(&self) -> Option<&str> {
+        self.entry_points
+            .iter()
+            .find(|entry_point| entry_point.entry_point_type == "video")
+            .map(|entry_point| entry_point.uri.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryPoint {
+    #[serde(default)]
+    pub entry_point_type: String,
+    #[serde(default)]
+    pub uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConferenceSolution {
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateConferenceRequest {
+    pub request_id: String,
+    pub conference_solution_key: ConferenceSolutionKey,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConferenceSolutionKey {
+    #[serde(rename = "type")]
+    pub key_type: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reminders {
+    #[serde(default)]
+    pub use_default: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<ReminderOverride>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReminderOverride {
+    pub method: String,
+    pub minutes: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    #[serde(default)]
+    pub file_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FreeBusy {
+    #[serde(default)]
+    pub calendars: HashMap<String, FreeBusyCalendar>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FreeBusyCalendar {
+    #[serde(default)]
+    pub busy: Vec<TimePeriod>,
+    #[serde(default)]
+    pub errors: Vec<FreeBusyError>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimePeriod {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FreeBusyError {
+    #[serde(default)]
+    pub reason: String,
+}
+
+pub trait CalendarApi: Debug + Send + Sync {
+    fn calendar(&self) -> Result<Calendar, ApiError>;
+
+    fn list_calendars(&self) -> Result<Vec<CalendarListEntry>, ApiError>;
+
+    fn list_events(&self, calendar_id: &str, time_min: DateTime<Utc>, time_max: DateTime<Utc>) -> Result<Vec<Event>, ApiError>;
+
+    fn list_instances(&self, calendar_id: &str, id: &str, time_max: DateTime<Utc>) -> Result<Vec<Event>, ApiError>;
+
+    fn get_event(&self, calendar_id: &str, id: &str) -> Result<Event, ApiError>;
+
+    fn insert_event(&self, calendar_id: &str, event: &Event) -> Result<(), ApiError>;
+
+    fn patch_event(&self, calendar_id: &str, id: &str, patch: &Value) -> Result<(), ApiError>;
+
+    fn delete_event(&self, calendar_id: &str, id: &str) -> Result<(), ApiError>;
+
+    fn free_busy(&self, ids: &[String], time_min: DateTime<Utc>, time_max: DateTime<Utc>) -> Result<FreeBusy, ApiError>;
+}
+
+#[derive(Debug)]
+pub struct HttpCalendar {
+    client: Client,
+    base_url: String,
+    tokens: TokenProvider,
+}
+
+impl HttpCalendar {
+    pub fn new(base_url: &str, tokens: TokenProvider) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            tokens,
+        }
+    }
+
+    fn calendar_url(&self, calendar_id: &str) -> String {
+        format!("{}/calendars/{}", self.base_url, api::segment(calendar_id))
+    }
+
+    fn calendar_list_url(&self) -> String {
+        format!("{}/users/me/calendarList", self.base_url)
+    }
+
+    fn events_url(&self, calendar_id: &str) -> String {
+        format!("{}/events", self.calendar_url(calendar_id))
+    }
+
+    fn event_url(&self, calendar_id: &str, id: &str) -> String {
+        format!("{}/{}", self.events_url(calendar_id), api::segment(id))
+    }
+
+    fn free_busy_url(&self) -> String {
+        format!("{}/freeBusy", self.base_url)
+    }
+}
+
+impl CalendarApi for HttpCalendar {
+    fn calendar(&self) -> Result<Calendar, ApiError> {
+        let url = self.calendar_url(PRIMARY);
+
+        Ok(api::send(&self.tokens, || self.client.get(&url))?.json()?)
+    }
+
+    fn list_calendars(&self) -> Result<Vec<CalendarListEntry>, ApiError> {
+        let url = self.calendar_list_url();
+
+        api::list(&self.tokens, || self.client.get(&url))
+    }
+
+    fn list_events(&self, calendar_id: &str, time_min: DateTime<Utc>, time_max: DateTime<Utc>) -> Result<Vec<Event>, ApiError> {
+        let url = self.events_url(calendar_id);
+        let time_min = time_min.to_rfc3339_opts(SecondsFormat::Secs, true);
+        let time_max = time_max.to_rfc3339_opts(SecondsFormat::Secs, true);
+
+        api::list(&self.tokens, || self.client
+            .get(&url)
+            .query(&[
+                ("orderBy", "startTime"),
+                ("singleEvents", "true"),
+                ("timeMin", time_min.as_str()),
+                ("timeMax", time_max.as_str()),
+            ]))
+    }
+
+    fn list_instances(&self, calendar_id: &str, id: &str, time_max: DateTime<Utc>) -> Result<Vec<Event>, ApiError> {
+        let url = format!("{}/instances", self.event_url(calendar_id, id));
+        let time_max = time_max.to_rfc3339_opts(SecondsFormat::Secs, true);
+
+        api::list(&self.tokens, || self.client
+            .get(&url)
+            .query(&[
+                ("showDeleted", "true"),
+                ("timeMax", time_max.as_str()),
+            ]))
+    }
+
+    fn get_event(&self, calendar_id: &str, id: &str) -> Result<Event, ApiError> {
+        let url = self.event_url(calendar_id, id);
+
+        Ok(api::send(&self.tokens, || self.client.get(&url))?.json()?)
+    }
+
+    fn insert_event(&self, calendar_id: &str, event: &Event) -> Result<(), ApiError> {
+        let url = self.events_url(calendar_id);
+
+        api::send(&self.tokens, || self.client.post(&url).query(&[CONFERENCE_DATA_VERSION]).json(event))?;
+        Ok(())
+    }
+
+    fn patch_event(&self, calendar_id: &str, id: &str, patch: &Value) -> Result<(), ApiError> {
+        let url = self.event_url(calendar_id, id);
+
+        api::send(&self.tokens, || self.client.patch(&url).query(&[CONFERENCE_DATA_VERSION]).json(patch))?;
+        Ok(())
+    }
+
+    fn delete_event(&self, calendar_id: &str, id: &str) -> Result<(), ApiError> {
+        let url = self.event_url(calendar_id, id);
+
+        api::send(&self.tokens, || self.client.delete(&url))?;
+        Ok(())
+    }
+
+    fn free_busy(&self, ids: &[String], time_min: DateTime<Utc>, time_max: DateTime<Utc>) -> Result<FreeBusy, ApiError> {
+        let url = self.free_busy_url();
+        let body = json!({
+            "timeMin": time_min.to_rfc3339_opts(SecondsFormat::Secs, true),
+            "timeMax": time_max.to_rfc3339_opts(SecondsFormat::Secs, true),
+            "items": ids.iter().map(|id| json!({ "id": id })).collect::<Vec<Value>>(),
+        });
+
+        Ok(api::send(&self.tokens, || self.client.post(&url).json(&body))?.json()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    use super::*;
+    use crate::app::google::stub::{StubServer, ACCESS_TOKEN};
+
+    const EVENTS_PATH: &str = "/calendars/primary/events";
+
+    fn calendar(server: &StubServer) -> HttpCalendar {
+        HttpCalendar::new(&format!("{}/", server.url), server.tokens())
+    }
+
+    fn utc(text: &str) -> DateTime<Utc> {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn lists_events_across_pages() {
+        let server = StubServer::start(|request| {
+            let body = match request.query("pageToken").as_deref() {
+                None => json!({ "items": [{ "id": "1" }, { "id": "2" }], "nextPageToken": "next" }),
+                Some("next") => json!({ "items": [{ "id": "3" }] }),
+                Some(_) => json!({}),
+            };
+            (200, body.to_string())
+        });
+
+        let events = calendar(&server)
+            .list_events(PRIMARY, utc("2024-01-01T00:00:00Z"), utc("2024-01-02T00:00:00Z"))
+            .unwrap();
+
+        let ids: Vec<&str> = events.iter().map(|event| event.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "3"]);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].path(), EVENTS_PATH);
+        assert_eq!(requests[0].query("timeMin").as_deref(), Some("2024-01-01T00:00:00Z"));
+        assert_eq!(requests[0].query("timeMax").as_deref(), Some("2024-01-02T00:00:00Z"));
+        assert_eq!(requests[0].query("singleEvents").as_deref(), Some("true"));
+        assert_eq!(requests[1].query("pageToken").as_deref(), Some("next"));
+        assert_eq!(requests[0].authorization, Some(format!("Bearer {}", ACCESS_TOKEN)));
+    }
+
+    #[test]
+    fn reads_the_calendar_time_zone() {
+        let server = StubServer::start(|_| {
+            (200, json!({ "id": "me@example.com", "timeZone": "Europe/Berlin" }).to_string())
+        });
+
+        let calendar = calendar(&server).calendar().unwrap();
+
+        assert_eq!(calendar.time_zone.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(server.requests()[0].path(), "/calendars/primary");
+    }
+
+    #[test]
+    fn lists_calendars_and_encodes_calendar_ids() {
+        let server = StubServer::start(|request| match request.path() {
+            "/users/me/calendarList" => (200, json!({ "items": [
+                { "id": "me@example.com", "summary": "Me", "primary": true, "selected": true, "accessRole": "owner", "backgroundColor": "#9fe1e7" },
+                { "id": "ja.japanese#holiday@group.v.calendar.google.com", "summary": "Holidays", "summaryOverride": "Japan", "accessRole": "reader" },
+            ] }).to_string()),
+            _ => (200, "{}".to_string()),
+        });
+        let calendar = calendar(&server);
+
+        let calendars = calendar.list_calendars().unwrap();
+        calendar
+            .list_events(&calendars[1].id, utc("2024-01-01T00:00:00Z"), utc("2024-01-02T00:00:00Z"))
+            .unwrap();
+
+        assert_eq!(calendars.len(), 2);
+        assert!(calendars[0].primary && calendars[0].selected && calendars[0].is_writable());
+        assert_eq!(calendars[0].background_color.as_deref(), Some("#9fe1e7"));
+        assert_eq!(calendars[1].name(), "Japan");
+        assert!(!calendars[1].is_writable());
+        assert_eq!(server.requests()[1].path(), "/calendars/ja.japanese%23holiday@group.v.calendar.google.com/events");
+    }
+
+    #[test]
+    fn lists_no_events_without_items() {
+        let server = StubServer::start(|_| (200, "{}".to_string()));
+
+        let events = calendar(&server)
+            .list_events(PRIMARY, utc("2024-01-01T00:00:00Z"), utc("2024-01-02T00:00:00Z"))
+            .unwrap();
+
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn parses_timed_and_all_day_events() {
+        let server = StubServer::start(|_| {
+            (200, json!({ "items": [
+                {
+                    "id": "timed",
+                    "summary": "Say \"hi\"",
+                    "start": { "dateTime": "2024-01-01T09:00:00+09:00", "timeZone": "Asia/Tokyo" },
+                    "end": { "dateTime": "2024-01-01T10:00:00+09:00" },
+                    "attendees": [{ "email": "a@example.com", "responseStatus": "accepted", "self": true }],
+                    "recurrence": ["RRULE:FREQ=WEEKLY"],
+                    "colorId": "5",
+                },
+                {
+                    "id": "all-day",
+                    "start": { "date": "2024-01-02" },
+                    "end": { "date": "2024-01-03" },
+                },
+            ] }).to_string())
+        });
+
+        let events = calendar(&server)
+            .list_events(PRIMARY, utc("2024-01-01T00:00:00Z"), utc("2024-01-03T00:00:00Z"))
+            .unwrap();
+
+        let timed = &events[0];
+        assert_eq!(timed.summary.as_deref(), Some("Say \"hi\""));
+        assert_eq!(timed.description, None);
+        assert_eq!(timed.start.in_zone(&Utc), Some(utc("2024-01-01T00:00:00Z")));
+        assert_eq!(timed.start.time_zone.as_deref(), Some("Asia/Tokyo"));
+        assert_eq!(timed.attendees[0].response_status.as_deref(), Some("accepted"));
+        assert!(timed.attendees[0].is_self);
+        assert_eq!(timed.recurrence, ["RRULE:FREQ=WEEKLY"]);
+        assert_eq!(timed.color_id.as_deref(), Some("5"));
+
+        let all_day = &events[1];
+        assert_eq!(all_day.summary, None);
+        assert_eq!(all_day.start.date, NaiveDate::from_ymd_opt(2024, 1, 2));
+        assert_eq!(all_day.start.date_time, None);
+    }
+
+    #[test]
+    fn gets_a_recurring_instance() {
+        let server = StubServer::start(|_| {
+            (200, json!({
+                "id": "series_20240108T000000Z",
+                "recurringEventId": "series",
+                "originalStartTime": { "dateTime": "2024-01-08T00:00:00Z" },
+                "start": { "dateTime": "2024-01-08T01:00:00Z" },
+                "end": { "dateTime": "2024-01-08T02:00:00Z" },
+            }).to_string())
+        });
+
+        let event = calendar(&server).get_event(PRIMARY, "series_20240108T000000Z").unwrap();
+
+        assert_eq!(server.requests()[0].path(), "/calendars/primary/events/series_20240108T000000Z");
+        assert_eq!(event.recurring_event_id.as_deref(), Some("series"));
+        assert_eq!(
+            event.original_start_time.and_then(|time| time.in_zone(&Utc)),
+            Some(utc("2024-01-08T00:00:00Z")),
+        );
+    }
+
+    #[test]
+    fn parses_conference_data_and_attachments() {
+        let server = StubServer::start(|_| {
+            (200, json!({
+                "id": "meeting",
+                "location": "Room 1",
+                "organizer": { "email": "boss@example.com", "displayName": "Boss" },
+                "conferenceData": {
+                    "entryPoints": [
+                        { "entryPointType": "phone", "uri": "tel:+1-555-0100", "label": "+1 555-0100" },
+                        { "entryPointType": "video", "uri": "https://meet.google.com/abc-defg-hij" },
+                    ],
+                    "conferenceSolution": { "name": "Google Meet" },
+                },
+                "attachments": [{ "fileUrl": "https://drive.google.com/file/1", "title": "Agenda" }],
+            }).to_string())
+        });
+
+        let event = calendar(&server).get_event(PRIMARY, "meeting").unwrap();
+
+        assert_eq!(event.location.as_deref(), Some("Room 1"));
+        assert_eq!(event.organizer.and_then(|person| person.display_name).as_deref(), Some("Boss"));
+        let conference = event.conference_data.unwrap();
+        assert_eq!(conference.video_uri(), Some("https://meet.google.com/abc-defg-hij"));
+        assert_eq!(conference.conference_solution.map(|solution| solution.name).as_deref(), Some("Google Meet"));
+        assert_eq!(event.attachments[0].title.as_deref(), Some("Agenda"));
+        assert_eq!(event.attachments[0].file_url, "https://drive.google.com/file/1");
+    }
+
+    #[test]
+    fn adds_patches_and_deletes_events() {
+        let server = StubServer::start(|_| (200, "{}".to_string()));
+        let calendar = calendar(&server);
+        let start = utc("2024-01-01T00:00:00Z");
+        let event = Event {
+            summary: Some("Meeting".to_string()),
+            start: EventTime::at(&start, "UTC"),
+            end: EventTime::at(&(start + chrono::Duration::hours(1)), "UTC"),
+            ..Event::default()
+        };
+        let patch = json!({ "summary": "Renamed" });
+
+        calendar.insert_event(PRIMARY, &event).unwrap();
+        calendar.patch_event(PRIMARY, "abc", &patch).unwrap();
+        calendar.delete_event(PRIMARY, "abc").unwrap();
+
+        let requests = server.requests();
+        let calls: Vec<(&str, &str)> = requests
+            .iter()
+            .map(|request| (request.method.as_str(), request.path()))
+            .collect();
+        assert_eq!(calls, [
+            ("POST", EVENTS_PATH),
+            ("PATCH", "/calendars/primary/events/abc"),
+            ("DELETE", "/calendars/primary/events/abc"),
+        ]);
+        assert_eq!(requests[0].json(), json!({
+            "summary": "Meeting",
+            "start": { "dateTime": "2024-01-01T00:00:00Z", "timeZone": "UTC" },
+            "end": { "dateTime": "2024-01-01T01:00:00Z", "timeZone": "UTC" },
+        }));
+        assert_eq!(requests[1].json(), patch);
+        assert_eq!(requests[0].query("conferenceDataVersion").as_deref(), Some("1"));
+        assert_eq!(requests[1].query("conferenceDataVersion").as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn queries_free_busy() {
+        let server = StubServer::start(|_| {
+            (200, json!({ "calendars": {
+                "primary": { "busy": [{ "start": "2024-01-01T09:00:00+09:00", "end": "2024-01-01T10:00:00+09:00" }] },
+                "other@example.com": { "busy": [], "errors": [{ "domain": "global", "reason": "notFound" }] },
+            } }).to_string())
+        });
+        let ids = [PRIMARY.to_string(), "other@example.com".to_string()];
+
+        let free_busy = calendar(&server)
+            .free_busy(&ids, utc("2024-01-01T00:00:00Z"), utc("2024-01-02T00:00:00Z"))
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path(), "/freeBusy");
+        assert_eq!(requests[0].json(), json!({
+            "timeMin": "2024-01-01T00:00:00Z",
+            "timeMax": "2024-01-02T00:00:00Z",
+            "items": [{ "id": "primary" }, { "id": "other@example.com" }],
+        }));
+        assert_eq!(free_busy.calendars[PRIMARY].busy, [TimePeriod {
+            start: utc("2024-01-01T00:00:00Z"),
+            end: utc("2024-01-01T01:00:00Z"),
+        }]);
+        assert_eq!(free_busy.calendars["other@example.com"].errors[0].reason, "notFound");
+    }
+
+    #[test]
+    fn surfaces_google_error_messages() {
+        let server = StubServer::start(|_| {
+            (403, json!({ "error": { "code": 403, "message": "Insufficient Permission" } }).to_string())
+        });
+
+        let error = calendar(&server).delete_event(PRIMARY, "abc").unwrap_err();
+
+        match error {
+            ApiError::Status { status, message } => {
+                assert_eq!(status, StatusCode::FORBIDDEN);
+                assert_eq!(message, "Insufficient Permission");
+            },
+            error => panic!("unexpected error: {}", error),
+        }
+    }
+
+    #[test]
+    fn refreshes_the_token_after_unauthorized() {
+        let server = StubServer::start(|request| match request.path() {
+            "/token" => (200, json!({
+                "access_token": "refreshed",
+                "token_type": "bearer",
+                "expires_in": 3600,
+            }).to_string()),
+            _ if request.authorization.as_deref() == Some("Bearer refreshed") => (200, "{}".to_string()),
+            _ => (401, json!({ "error": { "code": 401, "message": "Invalid Credentials" } }).to_string()),
+        });
+
+        calendar(&server).insert_event(PRIMARY, &Event::default()).unwrap();
+
+        let requests = server.requests();
+        let paths: Vec<&str> = requests.iter().map(|request| request.path()).collect();
+        assert_eq!(paths, [EVENTS_PATH, "/token", EVENTS_PATH]);
+        assert!(requests[1].body.contains("grant_type=refresh_token"));
+    }
+}
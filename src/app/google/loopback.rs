@@ -0,0 +1,332 @@
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{Ipv4Addr, TcpListener, TcpStream},
+    time::Duration,
+};
+
+use oauth2::{
+    basic::{BasicClient, BasicTokenResponse},
+    reqwest::http_client,
+    url::Url,
+    AuthorizationCode,
+    CsrfToken,
+    PkceCodeChallenge,
+    RedirectUrl,
+    Scope,
+};
+
+use crate::app::google::authentication::AuthError;
+
+const SUCCESS_PAGE: &str = "<html><body><h1>Authorization complete</h1><p>You can close this window and return to rust-tui.</p></body></html>";
+const FAILURE_PAGE: &str = "<html><body><h1>Authorization failed</h1><p>Return to rust-tui for details.</p></body></html>";
+const READ_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Debug, PartialEq)]
+enum Callback {
+    Code {
+        code: String,
+        state: String,
+    },
+    Error(String),
+}
+
+pub fn authorize<F>(client: BasicClient, scopes: &[&str], open: F) -> Result<BasicTokenResponse, AuthError>
+where
+    F: FnOnce(&str) -> io::Result<()>,
+{
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).map_err(AuthError::Listen)?;
+    let port = listener.local_addr().map_err(AuthError::Listen)?.port();
+
+    let redirect_url = RedirectUrl::new(format!("http://{}:{}/", Ipv4Addr::LOCALHOST, port))
+        .map_err(|source| AuthError::InvalidUrl { name: "redirect_uri", source })?;
+    let client = client.set_redirect_uri(redirect_url);
+
+    let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
+    let (auth_url, csrf_token) = client
+        .authorize_url(CsrfToken::new_random)
+        .add_scopes(scopes.iter().map(|scope| Scope::new(scope.to_string())))
+        .set_pkce_challenge(pkce_challenge)
+        .url();
+
+    if open(auth_url.as_str()).is_err() {
+        println!("Open the following URL in a browser to authorize the application: {}", auth_url);
+    } else {
+        println!("Waiting for authorization in the browser...");
+    }
+
+    let code = wait_for_code(&listener, &csrf_token)?;
+
+    client
+        .exchange_code(code)
+        .set_pkce_verifier(pkce_verifier)
+        .request(http_client)
+        .map_err(AuthError::Exchange)
+}
+
+fn wait_for_code(listener: &TcpListener, csrf_token: &CsrfToken) -> Result<AuthorizationCode, AuthError> {
+    for stream in listener.incoming() {
+        match stream.and_then(|stream| handle_callback(stream, csrf_token)) {
+            Ok(Some(result)) => return result,
+            Ok(None) => {},
+            Err(err) => eprintln!("Ignoring a broken authorization callback: {}", err),
+        }
+    }
+
+    Err(AuthError::Callback(io::ErrorKind::UnexpectedEof.into()))
+}
+
+fn handle_callback(
+    mut stream: TcpStream,
+    csrf_token: &CsrfToken,
+) -> io::Result<Option<Result<AuthorizationCode, AuthError>>> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    stream.set_write_timeout(Some(READ_TIMEOUT))?;
+
+    let Some(callback) = read_callback(&stream)? else {
+        respond(&mut stream, "404 Not Found", "")?;
+        return Ok(None);
+    };
+
+    let result = match callback {
+        Callback::Code { state, .. } if state != *csrf_token.secret() => Err(AuthError::CsrfMismatch),
+        Callback::Code { code, .. } => Ok(AuthorizationCode::new(code)),
+        Callback::Error(error) => Err(AuthError::Denied(error)),
+    };
+
+    let page = if result.is_ok() { SUCCESS_PAGE } else { FAILURE_PAGE };
+    if let Err(err) = respond(&mut stream, "200 OK", page) {
+        eprintln!("Failed to answer the authorization callback: {}", err);
+    }
+
+    Ok(Some(result))
+}
+
+fn read_callback(stream: &TcpStream) -> io::Result<Option<Callback>> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let target = match request_line.split_whitespace().collect::<Vec<_>>()[..] {
+        ["GET", target, _] => target,
+        _ => return Ok(None),
+    };
+
+    Ok(parse_callback(target))
+}
+
+fn parse_callback(target: &str) -> Option<Callback> {
+    let url = Url::parse(&format!("http://{}{}", Ipv4Addr::LOCALHOST, target)).ok()?;
+    if url.path() != "/" {
+        return None;
+    }
+
+    let param = |name: &str| {
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    };
+
+    if let Some(error) = param("error") {
+        return Some(Callback::Error(error));
+    }
+
+    Some(Callback::Code {
+        code: param("code")?,
+        state: param("state")?,
+    })
+}
+
+fn respond(stream: &mut TcpStream, status: &str, body: &str) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body,
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use oauth2::{
+        AuthUrl,
+        ClientId,
+        ClientSecret,
+        PkceCodeVerifier,
+        TokenResponse,
+        TokenUrl,
+    };
+    use tiny_http::{Header, Method, Response, Server};
+
+    use super::*;
+
+    const CODE: &str = "fake-authorization-code";
+
+    #[derive(Clone, Copy)]
+    enum Behavior {
+        Approve,
+        WrongState,
+        Deny,
+    }
+
+    struct FakeAuthServer {
+        url: String,
+        challenge: Arc<Mutex<Option<String>>>,
+    }
+
+    impl FakeAuthServer {
+        fn start(behavior: Behavior) -> Self {
+            let server = Server::http("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+            let challenge = Arc::new(Mutex::new(None));
+
+            let shared = Arc::clone(&challenge);
+            thread::spawn(move || {
+                for mut request in server.incoming_requests() {
+                    let url = Url::parse(&format!("http://localhost{}", request.url())).unwrap();
+                    let response = match (request.method(), url.path()) {
+                        (Method::Get, "/auth") => {
+                            let query: HashMap<_, _> = url.query_pairs().into_owned().collect();
+                            assert_eq!(query["code_challenge_method"], "S256");
+                            *shared.lock().unwrap() = Some(query["code_challenge"].clone());
+
+                            let mut redirect = Url::parse(&query["redirect_uri"]).unwrap();
+                            match behavior {
+                                Behavior::Approve => redirect.query_pairs_mut()
+                                    .append_pair("code", CODE)
+                                    .append_pair("state", &query["state"]),
+                                Behavior::WrongState => redirect.query_pairs_mut()
+                                    .append_pair("code", CODE)
+                                    .append_pair("state", "forged"),
+                                Behavior::Deny => redirect.query_pairs_mut()
+                                    .append_pair("error", "access_denied"),
+                            };
+
+                            let location = Header::from_bytes("Location", redirect.as_str()).unwrap();
+                            Response::from_string("").with_status_code(302).with_header(location)
+                        },
+                        (Method::Post, "/token") => {
+                            let mut body = String::new();
+                            request.as_reader().read_to_string(&mut body).unwrap();
+                            let form: HashMap<_, _> = form_urlencoded(&body);
+
+                            let verifier = PkceCodeVerifier::new(form["code_verifier"].clone());
+                            let expected = PkceCodeChallenge::from_code_verifier_sha256(&verifier);
+                            let challenge = shared.lock().unwrap().clone();
+
+                            if form["code"] == CODE && challenge.as_deref() == Some(expected.as_str()) {
+                                let json = Header::from_bytes("Content-Type", "application/json").unwrap();
+                                Response::from_string(r#"{"access_token":"fake-access","token_type":"Bearer","expires_in":3600,"refresh_token":"fake-refresh"}"#)
+                                    .with_header(json)
+                            } else {
+                                Response::from_string(r#"{"error":"invalid_grant"}"#).with_status_code(400)
+                            }
+                        },
+                        _ => Response::from_string("").with_status_code(404),
+                    };
+                    let _ = request.respond(response);
+                }
+            });
+
+            Self { url, challenge }
+        }
+
+        fn client(&self) -> BasicClient {
+            BasicClient::new(
+                ClientId::new("client".to_string()),
+                Some(ClientSecret::new("secret".to_string())),
+                AuthUrl::new(format!("{}/auth", self.url)).unwrap(),
+                Some(TokenUrl::new(format!("{}/token", self.url)).unwrap()),
+            )
+        }
+    }
+
+    fn form_urlencoded(body: &str) -> HashMap<String, String> {
+        Url::parse(&format!("http://localhost/?{}", body))
+            .unwrap()
+            .query_pairs()
+            .into_owned()
+            .collect()
+    }
+
+    fn browser(url: &str) -> io::Result<()> {
+        let url = url.to_string();
+        thread::spawn(move || reqwest::blocking::get(url));
+        Ok(())
+    }
+
+    #[test]
+    fn exchanges_code_with_pkce_verifier() {
+        let server = FakeAuthServer::start(Behavior::Approve);
+
+        let token = authorize(server.client(), &["scope"], browser).unwrap();
+
+        assert_eq!(token.access_token().secret(), "fake-access");
+        assert_eq!(token.refresh_token().unwrap().secret(), "fake-refresh");
+        assert!(server.challenge.lock().unwrap().is_some());
+    }
+
+    #[test]
+    fn rejects_mismatched_state() {
+        let server = FakeAuthServer::start(Behavior::WrongState);
+
+        let result = authorize(server.client(), &["scope"], browser);
+
+        assert!(matches!(result, Err(AuthError::CsrfMismatch)));
+    }
+
+    #[test]
+    fn reports_denied_consent() {
+        let server = FakeAuthServer::start(Behavior::Deny);
+
+        let result = authorize(server.client(), &["scope"], browser);
+
+        assert!(matches!(result, Err(AuthError::Denied(error)) if error == "access_denied"));
+    }
+
+    #[test]
+    fn skips_silent_connections_before_the_callback() {
+        let server = FakeAuthServer::start(Behavior::Approve);
+
+        let token = authorize(server.client(), &["scope"], |url| {
+            let redirect = Url::parse(url).unwrap()
+                .query_pairs()
+                .find(|(key, _)| key == "redirect_uri")
+                .map(|(_, value)| Url::parse(&value).unwrap())
+                .unwrap();
+            let silent = TcpStream::connect((Ipv4Addr::LOCALHOST, redirect.port().unwrap()))?;
+            thread::spawn(move || {
+                thread::sleep(READ_TIMEOUT * 2);
+                drop(silent);
+            });
+
+            browser(url)
+        })
+        .unwrap();
+
+        assert_eq!(token.access_token().secret(), "fake-access");
+    }
+
+    #[test]
+    fn ignores_unrelated_requests() {
+        assert_eq!(parse_callback("/favicon.ico"), None);
+        assert_eq!(parse_callback("/?state=abc"), None);
+        assert_eq!(
+            parse_callback("/?code=a%2Fb&state=xyz"),
+            Some(Callback::Code { code: "a/b".to_string(), state: "xyz".to_string() }),
+        );
+    }
+}
@@ -1 +1,10 @@
-pub mod authentication;
\ No newline at end of file
+pub mod account;
+pub mod api;
+pub mod authentication;
+pub mod calendar;
+pub mod loopback;
+pub mod storage;
+#[cfg(test)]
+pub mod stub;
+pub mod tasks;
+pub mod token;
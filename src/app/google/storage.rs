@@ -0,0 +1,314 @@
+use std::{
+    env,
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use argon2::Argon2;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, rand_core::RngCore},
+    ChaCha20Poly1305,
+    Key,
+    Nonce,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::app::google::{
+    authentication::AuthError,
+    token::TokenInfo,
+};
+
+const PASSPHRASE_VAR: &str = "RUST_TUI_PASSPHRASE";
+const SALT_LEN: usize = 16;
+
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    version: u8,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TokenFile {
+    Encrypted(Envelope),
+    Plain(TokenInfo),
+}
+
+pub struct TokenStore {
+    path: PathBuf,
+    passphrase: Option<String>,
+    key: Option<([u8; SALT_LEN], Key)>,
+}
+
+impl fmt::Debug for TokenStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenStore")
+            .field("path", &self.path)
+            .field("encrypted", &self.passphrase.is_some())
+            .finish()
+    }
+}
+
+impl TokenStore {
+    pub fn new(path: &Path, passphrase: Option<String>) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            passphrase,
+            key: None,
+        }
+    }
+
+    pub fn load(&mut self) -> Result<Option<TokenInfo>, AuthError> {
+        let data = match fs::read_to_string(&self.path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => return Err(AuthError::ReadToken { path: self.path.clone(), source }),
+        };
+        restrict(&self.path).map_err(|source| AuthError::ReadToken { path: self.path.clone(), source })?;
+
+        let file = serde_json::from_str(&data)
+            .map_err(|source| AuthError::ParseToken { path: self.path.clone(), source })?;
+
+        match file {
+            TokenFile::Plain(token_info) => Ok(Some(token_info)),
+            TokenFile::Encrypted(envelope) => self.decrypt(&envelope).map(Some),
+        }
+    }
+
+    pub fn save(&mut self, token_info: &TokenInfo) -> Result<(), AuthError> {
+        let json = match self.passphrase {
+            Some(_) => serde_json::to_string(&self.encrypt(token_info)?),
+            None => serde_json::to_string(token_info),
+        }
+        .map_err(|source| AuthError::ParseToken { path: self.path.clone(), source })?;
+
+        write_private(&self.path, json.as_bytes())
+            .map_err(|source| AuthError::SaveToken { path: self.path.clone(), source })
+    }
+
+    pub fn delete(&self) -> Result<(), AuthError> {
+        match fs::remove_file(&self.path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => {
+                Err(AuthError::DeleteToken { path: self.path.clone(), source: err })
+            },
+            _ => Ok(()),
+        }
+    }
+
+    fn derive(passphrase: &str, salt: &[u8]) -> Result<Key, AuthError> {
+        let mut key = Key::default();
+        Argon2::default()
+            .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+            .map_err(|err| AuthError::Cipher(err.to_string()))?;
+
+        Ok(key)
+    }
+
+    fn encrypt(&mut self, token_info: &TokenInfo) -> Result<Envelope, AuthError> {
+        let (salt, key) = match self.key {
+            Some(key) => key,
+            None => {
+                let mut salt = [0; SALT_LEN];
+                OsRng.fill_bytes(&mut salt);
+                let key = Self::derive(self.passphrase.as_deref().unwrap_or_default(), &salt)?;
+                self.key = Some((salt, key));
+                (salt, key)
+            },
+        };
+
+        let plaintext = serde_json::to_vec(token_info)
+            .map_err(|source| AuthError::ParseToken { path: self.path.clone(), source })?;
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = ChaCha20Poly1305::new(&key)
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|err| AuthError::Cipher(err.to_string()))?;
+
+        Ok(Envelope {
+            version: 1,
+            salt: BASE64.encode(salt),
+            nonce: BASE64.encode(nonce),
+            ciphertext: BASE64.encode(ciphertext),
+        })
+    }
+
+    fn decrypt(&mut self, envelope: &Envelope) -> Result<TokenInfo, AuthError> {
+        let corrupt = || AuthError::Decrypt { path: self.path.clone() };
+
+        let salt: [u8; SALT_LEN] = BASE64.decode(&envelope.salt).ok()
+            .and_then(|salt| salt.try_into().ok())
+            .ok_or_else(corrupt)?;
+        let nonce = BASE64.decode(&envelope.nonce).ok()
+            .filter(|nonce| nonce.len() == Nonce::default().len())
+            .ok_or_else(corrupt)?;
+        let ciphertext = BASE64.decode(&envelope.ciphertext).map_err(|_| corrupt())?;
+
+        if self.passphrase.is_none() {
+            self.passphrase = Some(passphrase()?);
+        }
+        let key = Self::derive(self.passphrase.as_deref().unwrap_or_default(), &salt)?;
+        let plaintext = ChaCha20Poly1305::new(&key)
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| corrupt())?;
+        let token_info = serde_json::from_slice(&plaintext)
+            .map_err(|source| AuthError::ParseToken { path: self.path.clone(), source })?;
+
+        self.key = Some((salt, key));
+
+        Ok(token_info)
+    }
+}
+
+pub fn passphrase() -> Result<String, AuthError> {
+    if let Ok(passphrase) = env::var(PASSPHRASE_VAR) {
+        return Ok(passphrase);
+    }
+
+    rpassword::prompt_password("Token passphrase: ").map_err(AuthError::Passphrase)
+}
+
+pub fn write_private(path: &Path, contents: &[u8]) -> io::Result<()> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        create_private_dir(dir)?;
+    }
+
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(path)?;
+    restrict(path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
+
+pub fn restrict(path: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let permissions = fs::metadata(path)?.permissions();
+        if permissions.mode() & 0o077 != 0 {
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+
+    Ok(())
+}
+
+fn create_private_dir(dir: &Path) -> io::Result<()> {
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(0o700);
+    }
+
+    builder.create(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use chrono::Utc;
+
+    use super::*;
+
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new() -> Self {
+            let id = NEXT_ID.fetch_add(1, Ordering::SeqCst);
+
+            Self(env::temp_dir().join(format!("rust-tui-storage-{}-{}", std::process::id(), id)).join("token.json"))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            if let Some(dir) = self.0.parent() {
+                let _ = fs::remove_dir_all(dir);
+            }
+        }
+    }
+
+    fn token_info() -> TokenInfo {
+        TokenInfo {
+            access_token: "secret-access".to_string(),
+            refresh_token: "secret-refresh".to_string(),
+            expires_at: Some(Utc::now()),
+        }
+    }
+
+    fn passphrase(text: &str) -> Option<String> {
+        Some(text.to_string())
+    }
+
+    #[test]
+    fn round_trips_encrypted_tokens() {
+        let file = TempFile::new();
+        let saved = token_info();
+        TokenStore::new(&file.0, passphrase("correct horse")).save(&saved).unwrap();
+
+        let contents = fs::read_to_string(&file.0).unwrap();
+        assert!(!contents.contains("secret-access") && !contents.contains("secret-refresh"));
+
+        let loaded = TokenStore::new(&file.0, passphrase("correct horse")).load().unwrap().unwrap();
+        assert_eq!(loaded.access_token, "secret-access");
+        assert_eq!(loaded.refresh_token, "secret-refresh");
+        assert_eq!(loaded.expires_at, saved.expires_at);
+    }
+
+    #[test]
+    fn rejects_a_wrong_passphrase() {
+        let file = TempFile::new();
+        TokenStore::new(&file.0, passphrase("correct horse")).save(&token_info()).unwrap();
+
+        let result = TokenStore::new(&file.0, passphrase("battery staple")).load();
+
+        assert!(matches!(result, Err(AuthError::Decrypt { path }) if path == file.0));
+    }
+
+    #[test]
+    fn reads_plain_tokens_and_missing_files() {
+        let file = TempFile::new();
+        let mut store = TokenStore::new(&file.0, None);
+        assert!(store.load().unwrap().is_none());
+
+        store.save(&token_info()).unwrap();
+        assert_eq!(store.load().unwrap().unwrap().refresh_token, "secret-refresh");
+
+        store.delete().unwrap();
+        store.delete().unwrap();
+        assert!(store.load().unwrap().is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn keeps_token_files_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let file = TempFile::new();
+        TokenStore::new(&file.0, None).save(&token_info()).unwrap();
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&file.0), 0o600);
+        assert_eq!(mode(file.0.parent().unwrap()), 0o700);
+
+        fs::set_permissions(&file.0, fs::Permissions::from_mode(0o644)).unwrap();
+        restrict(&file.0).unwrap();
+        assert_eq!(mode(&file.0), 0o600);
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+        Mutex,
+    },
+    thread,
+};
+
+use chrono::{Duration, Utc};
+use oauth2::{basic::BasicClient, AuthUrl, ClientId, ClientSecret, TokenUrl};
+use tiny_http::{Header, Server};
+
+use crate::app::google::{
+    storage::TokenStore,
+    token::{TokenInfo, TokenProvider},
+};
+
+pub const ACCESS_TOKEN: &str = "stub-access";
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub body: String,
+    pub authorization: Option<String>,
+}
+
+impl Request {
+    pub fn path(&self) -> &str {
+        self.url.split('?').next().unwrap_or_default()
+    }
+
+    pub fn query(&self, name: &str) -> Option<String> {
+        let url = oauth2::url::Url::parse(&format!("http://localhost{}", self.url)).ok()?;
+
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    }
+
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_str(&self.body).unwrap_or_default()
+    }
+}
+
+pub struct StubServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+    token_path: PathBuf,
+}
+
+impl StubServer {
+    pub fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&Request) -> (u16, String) + Send + 'static,
+    {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for mut incoming in server.incoming_requests() {
+                let mut body = String::new();
+                let _ = incoming.as_reader().read_to_string(&mut body);
+
+                let request = Request {
+                    method: incoming.method().to_string(),
+                    url: incoming.url().to_string(),
+                    body,
+                    authorization: incoming.headers()
+                        .iter()
+                        .find(|header| header.field.equiv("Authorization"))
+                        .map(|header| header.value.to_string()),
+                };
+                recorded.lock().unwrap().push(request.clone());
+
+                let (status, body) = handler(&request);
+                let json = Header::from_bytes("Content-Type", "application/json").unwrap();
+                let response = tiny_http::Response::from_string(body)
+                    .with_status_code(status)
+                    .with_header(json);
+                let _ = incoming.respond(response);
+            }
+        });
+
+        let id = NEXT_ID.fetch_add(1, Ordering::SeqCst);
+        let token_path = std::env::temp_dir().join(format!("rust-tui-stub-{}-{}.json", std::process::id(), id));
+
+        Self { url, requests, token_path }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn tokens(&self) -> TokenProvider {
+        let client = BasicClient::new(
+            ClientId::new("client".to_string()),
+            Some(ClientSecret::new("secret".to_string())),
+            AuthUrl::new(format!("{}/auth", self.url)).unwrap(),
+            Some(TokenUrl::new(format!("{}/token", self.url)).unwrap()),
+        );
+        let token_info = TokenInfo {
+            access_token: ACCESS_TOKEN.to_string(),
+            refresh_token: "stub-refresh".to_string(),
+            expires_at: Some(Utc::now() + Duration::hours(1)),
+        };
+
+        TokenProvider::new(client, TokenStore::new(&self.token_path, None), token_info)
+    }
+}
+
+impl Drop for StubServer {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.token_path);
+    }
+}
@@ -0,0 +1,249 @@
+use std::fmt::Debug;
+
+use chrono::{DateTime, Utc};
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::app::google::{
+    api::{self, ApiError},
+    token::TokenProvider,
+};
+
+pub const BASE_URL: &str = "https://tasks.googleapis.com/tasks/v1";
+const TASK_LIST_ID: &str = "@default";
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Task {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<TaskLink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_view_link: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskLink {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub description: String,
+    pub link: String,
+}
+
+pub trait TasksApi: Debug + Send + Sync {
+    fn list_tasks(&self) -> Result<Vec<Task>, ApiError>;
+
+    fn insert_task(&self, task: &Task) -> Result<(), ApiError>;
+
+    fn patch_task(&self, id: &str, patch: &Value) -> Result<(), ApiError>;
+
+    fn complete_task(&self, id: &str) -> Result<(), ApiError>;
+
+    fn delete_task(&self, id: &str) -> Result<(), ApiError>;
+}
+
+#[derive(Debug)]
+pub struct HttpTasks {
+    client: Client,
+    base_url: String,
+    tokens: TokenProvider,
+}
+
+impl HttpTasks {
+    pub fn new(base_url: &str, tokens: TokenProvider) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            tokens,
+        }
+    }
+
+    fn tasks_url(&self) -> String {
+        format!("{}/lists/{}/tasks", self.base_url, api::segment(TASK_LIST_ID))
+    }
+
+    fn task_url(&self, id: &str) -> String {
+        format!("{}/{}", self.tasks_url(), api::segment(id))
+    }
+}
+
+impl TasksApi for HttpTasks {
+    fn list_tasks(&self) -> Result<Vec<Task>, ApiError> {
+        let url = self.tasks_url();
+
+        api::list(&self.tokens, || self.client
+            .get(&url)
+            .query(&[("showCompleted", "false")]))
+    }
+
+    fn insert_task(&self, task: &Task) -> Result<(), ApiError> {
+        let url = self.tasks_url();
+
+        api::send(&self.tokens, || self.client.post(&url).json(task))?;
+        Ok(())
+    }
+
+    fn patch_task(&self, id: &str, patch: &Value) -> Result<(), ApiError> {
+        let url = self.task_url(id);
+
+        api::send(&self.tokens, || self.client.patch(&url).json(patch))?;
+        Ok(())
+    }
+
+    fn complete_task(&self, id: &str) -> Result<(), ApiError> {
+        let url = self.task_url(id);
+        let body = json!({ "status": "completed" });
+
+        api::send(&self.tokens, || self.client.patch(&url).json(&body))?;
+        Ok(())
+    }
+
+    fn delete_task(&self, id: &str) -> Result<(), ApiError> {
+        let url = self.task_url(id);
+
+        api::send(&self.tokens, || self.client.delete(&url))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    use super::*;
+    use crate::app::google::stub::StubServer;
+
+    const TASKS_PATH: &str = "/lists/@default/tasks";
+
+    fn tasks(server: &StubServer) -> HttpTasks {
+        HttpTasks::new(&server.url, server.tokens())
+    }
+
+    #[test]
+    fn lists_open_tasks_across_pages() {
+        let server = StubServer::start(|request| {
+            let body = match request.query("pageToken") {
+                None => json!({ "items": [{ "id": "1" }], "nextPageToken": "second" }),
+                Some(_) => json!({ "items": [{ "id": "2" }] }),
+            };
+            (200, body.to_string())
+        });
+
+        let tasks = tasks(&server).list_tasks().unwrap();
+
+        let ids: Vec<&str> = tasks.iter().map(|task| task.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2"]);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].path(), TASKS_PATH);
+        assert_eq!(requests[0].query("showCompleted").as_deref(), Some("false"));
+        assert_eq!(requests[1].query("pageToken").as_deref(), Some("second"));
+    }
+
+    #[test]
+    fn parses_task_fields() {
+        let server = StubServer::start(|_| {
+            (200, json!({ "items": [{
+                "id": "1",
+                "title": "Quote \"this\"",
+                "status": "needsAction",
+                "due": "2024-01-05T00:00:00.000Z",
+                "updated": "2024-01-01T12:34:56.789Z",
+                "parent": "0",
+                "position": "00000000000000000001",
+                "links": [{ "type": "email", "description": "Mail", "link": "https://mail.example.com" }],
+            }] }).to_string())
+        });
+
+        let task = tasks(&server).list_tasks().unwrap().remove(0);
+
+        assert_eq!(task.title, "Quote \"this\"");
+        assert_eq!(task.notes, None);
+        assert_eq!(task.due, Some("2024-01-05T00:00:00Z".parse().unwrap()));
+        assert_eq!(task.completed, None);
+        assert_eq!(task.parent.as_deref(), Some("0"));
+        assert_eq!(task.links[0].kind, "email");
+    }
+
+    #[test]
+    fn adds_patches_completes_and_deletes_tasks() {
+        let server = StubServer::start(|_| (200, "{}".to_string()));
+        let tasks = tasks(&server);
+        let task = Task { title: "Write report".to_string(), ..Task::default() };
+        let patch = json!({ "notes": "Draft first" });
+
+        tasks.insert_task(&task).unwrap();
+        tasks.patch_task("xyz", &patch).unwrap();
+        tasks.complete_task("xyz").unwrap();
+        tasks.delete_task("xyz").unwrap();
+
+        let requests = server.requests();
+        let calls: Vec<(&str, &str)> = requests
+            .iter()
+            .map(|request| (request.method.as_str(), request.path()))
+            .collect();
+        assert_eq!(calls, [
+            ("POST", TASKS_PATH),
+            ("PATCH", "/lists/@default/tasks/xyz"),
+            ("PATCH", "/lists/@default/tasks/xyz"),
+            ("DELETE", "/lists/@default/tasks/xyz"),
+        ]);
+        assert_eq!(requests[0].json(), json!({ "title": "Write report" }));
+        assert_eq!(requests[1].json(), patch);
+        assert_eq!(requests[2].json(), json!({ "status": "completed" }));
+    }
+
+    #[test]
+    fn encodes_task_ids() {
+        let server = StubServer::start(|_| (200, "{}".to_string()));
+
+        tasks(&server).delete_task("a/b?c").unwrap();
+
+        assert_eq!(server.requests()[0].path(), "/lists/@default/tasks/a%2Fb%3Fc");
+    }
+
+    #[test]
+    fn surfaces_plain_error_bodies() {
+        let server = StubServer::start(|_| (404, "Not Found".to_string()));
+
+        let error = tasks(&server).complete_task("missing").unwrap_err();
+
+        match error {
+            ApiError::Status { status, message } => {
+                assert_eq!(status, StatusCode::NOT_FOUND);
+                assert_eq!(message, "Not Found");
+            },
+            error => panic!("unexpected error: {}", error),
+        }
+    }
+
+    #[test]
+    fn fails_when_the_server_is_unreachable() {
+        let server = StubServer::start(|_| (200, "{}".to_string()));
+        let tasks = HttpTasks::new("http://127.0.0.1:1", server.tokens());
+
+        assert!(matches!(tasks.list_tasks(), Err(ApiError::Request(_))));
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::{DateTime, Duration, Utc};
+use oauth2::{
+    basic::{BasicClient, BasicTokenResponse},
+    reqwest::http_client,
+    RefreshToken,
+    StandardRevocableToken,
+    TokenResponse,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::app::google::{
+    authentication::AuthError,
+    storage::TokenStore,
+};
+
+const EXPIRY_MARGIN: Duration = Duration::seconds(60);
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TokenInfo {
+    pub access_token: String,
+    pub refresh_token: String,
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl TokenInfo {
+    pub fn from_response(token: &BasicTokenResponse) -> Result<Self, AuthError> {
+        Ok(Self {
+            access_token: token.access_token().secret().clone(),
+            refresh_token: token.refresh_token()
+                .ok_or(AuthError::MissingRefreshToken)?
+                .secret()
+                .clone(),
+            expires_at: Self::expires_at(token),
+        })
+    }
+
+    pub fn is_expired(&self) -> bool {
+        match self.expires_at {
+            Some(expires_at) => expires_at - EXPIRY_MARGIN <= Utc::now(),
+            None => true,
+        }
+    }
+
+    fn expires_at(token: &BasicTokenResponse) -> Option<DateTime<Utc>> {
+        let expires_in = Duration::from_std(token.expires_in()?).ok()?;
+
+        Some(Utc::now() + expires_in)
+    }
+}
+
+#[derive(Debug)]
+struct TokenState {
+    client: BasicClient,
+    store: TokenStore,
+    token_info: Option<TokenInfo>,
+}
+
+impl TokenState {
+    fn token_info(&mut self) -> Result<&mut TokenInfo, AuthError> {
+        self.token_info.as_mut().ok_or(AuthError::SignedOut)
+    }
+
+    fn refresh(&mut self) -> Result<(), AuthError> {
+        let client = self.client.clone();
+        let token_info = self.token_info()?;
+
+        let refresh_token = RefreshToken::new(token_info.refresh_token.clone());
+        let token = client
+            .exchange_refresh_token(&refresh_token)
+            .request(http_client)
+            .map_err(AuthError::Refresh)?;
+
+        token_info.access_token = token.access_token().secret().clone();
+        token_info.expires_at = TokenInfo::expires_at(&token);
+        if let Some(refresh_token) = token.refresh_token() {
+            token_info.refresh_token = refresh_token.secret().clone();
+        }
+
+        let token_info = token_info.clone();
+        self.store.save(&token_info)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TokenProvider {
+    state: Arc<Mutex<TokenState>>,
+}
+
+impl TokenProvider {
+    pub fn new(client: BasicClient, store: TokenStore, token_info: TokenInfo) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(TokenState {
+                client,
+                store,
+                token_info: Some(token_info),
+            })),
+        }
+    }
+
+    pub fn access_token(&self) -> Result<String, AuthError> {
+        let mut state = self.lock();
+        if state.token_info()?.is_expired() {
+            state.refresh()?;
+        }
+
+        Ok(state.token_info()?.access_token.clone())
+    }
+
+    pub fn reject(&self, access_token: &str) -> Result<String, AuthError> {
+        let mut state = self.lock();
+        if state.token_info()?.access_token == access_token {
+            state.refresh()?;
+        }
+
+        Ok(state.token_info()?.access_token.clone())
+    }
+
+    pub fn replace(&self, token_info: TokenInfo) -> Result<(), AuthError> {
+        let mut state = self.lock();
+        state.store.save(&token_info)?;
+        state.token_info = Some(token_info);
+
+        Ok(())
+    }
+
+    pub fn logout(&self) -> Result<(), AuthError> {
+        let mut state = self.lock();
+        let token_info = state.token_info.take().ok_or(AuthError::SignedOut)?;
+        state.store.delete()?;
+
+        let refresh_token = StandardRevocableToken::RefreshToken(RefreshToken::new(token_info.refresh_token));
+        state.client
+            .revoke_token(refresh_token)
+            .map_err(AuthError::RevocationUrl)?
+            .request(http_client)
+            .map_err(AuthError::Revoke)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TokenState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::app::google::stub::{StubServer, ACCESS_TOKEN};
+
+    #[test]
+    fn recovers_from_a_revoked_refresh_token() {
+        let server = StubServer::start(|_| {
+            (400, json!({ "error": "invalid_grant", "error_description": "Token has been expired or revoked." }).to_string())
+        });
+        let tokens = server.tokens();
+
+        let error = tokens.reject(ACCESS_TOKEN).unwrap_err();
+        assert!(error.is_invalid_grant());
+
+        tokens.replace(TokenInfo {
+            access_token: "signed-in".to_string(),
+            refresh_token: "new-refresh".to_string(),
+            expires_at: Some(Utc::now() + Duration::hours(1)),
+        }).unwrap();
+        assert_eq!(tokens.access_token().unwrap(), "signed-in");
+    }
+}
@@ -0,0 +1,190 @@
+const BLOCK_TAGS: [&str; 13] = ["p", "div", "ul", "ol", "tr", "table", "blockquote", "h1", "h2", "h3", "h4", "h5", "h6"];
+const ENTITIES: [(&str, &str); 7] = [
+    ("amp", "&"),
+    ("lt", "<"),
+    ("gt", ">"),
+    ("quot", "\""),
+    ("apos", "'"),
+    ("nbsp", " "),
+    ("#39", "'"),
+];
+
+struct Tag {
+    name: String,
+    closing: bool,
+    href: Option<String>,
+}
+
+impl Tag {
+    fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().trim_end_matches('/').trim();
+        let (closing, text) = match text.strip_prefix('/') {
+            Some(text) => (true, text),
+            None => (false, text),
+        };
+
+        let name: String = text
+            .chars()
+            .take_while(|char| char.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+        if name.is_empty() {
+            return None;
+        }
+
+        let href = text
+            .find("href=")
+            .map(|index| &text[index + 5..])
+            .and_then(|value| {
+                let quote = value.chars().next().filter(|char| *char == '"' || *char == '\'')?;
+                value[1..].split(quote).next()
+            })
+            .map(decode);
+
+        Some(Self { name, closing, href })
+    }
+}
+
+pub fn is_html(text: &str) -> bool {
+    text.char_indices().any(|(index, char)| {
+        char == '<' && text[index + 1..].starts_with(|next: char| next.is_ascii_alphabetic() || next == '/')
+    })
+}
+
+pub fn to_text(text: &str) -> String {
+    if !is_html(text) {
+        return text.trim().to_string();
+    }
+
+    let mut output = String::new();
+    let mut link: Option<(String, usize)> = None;
+    let mut rest = text;
+
+    while let Some(start) = rest.find('<') {
+        output.push_str(&decode(&rest[..start]));
+
+        let Some(end) = rest[start..].find('>') else {
+            output.push_str(&decode(&rest[start..]));
+            rest = "";
+            break;
+        };
+        let tag = Tag::parse(&rest[start + 1..start + end]);
+        rest = &rest[start + end + 1..];
+
+        let Some(tag) = tag else {
+            continue;
+        };
+        match (tag.name.as_str(), tag.closing) {
+            ("br", _) => output.push('\n'),
+            ("li", false) => {
+                new_line(&mut output);
+                output.push_str("- ");
+            },
+            ("a", false) => link = tag.href.map(|href| (href, output.len())),
+            ("a", true) => {
+                if let Some((href, start)) = link.take() {
+                    if output[start..].trim() != href {
+                        output.push_str(&format!(" ({})", href));
+                    }
+                }
+            },
+            (name, _) if BLOCK_TAGS.contains(&name) || name == "li" => new_line(&mut output),
+            _ => {},
+        }
+    }
+    output.push_str(&decode(rest));
+
+    let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+    let mut text = String::new();
+    let mut blank = 0;
+    for line in lines {
+        blank = if line.is_empty() { blank + 1 } else { 0 };
+        if blank < 2 {
+            text.push_str(line);
+            text.push('\n');
+        }
+    }
+
+    text.trim().to_string()
+}
+
+fn new_line(output: &mut String) {
+    if !output.is_empty() && !output.ends_with('\n') {
+        output.push('\n');
+    }
+}
+
+fn decode(text: &str) -> String {
+    let mut output = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest[1..].find(';').filter(|end| *end <= 8).and_then(|end| {
+            let name = &rest[1..end + 1];
+            let char = match name.strip_prefix('#') {
+                Some(code) => match code.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => code.parse().ok(),
+                }
+                .and_then(char::from_u32)
+                .map(String::from),
+                None => None,
+            };
+            let char = char.or_else(|| {
+                ENTITIES
+                    .iter()
+                    .find(|(entity, _)| *entity == name)
+                    .map(|(_, char)| char.to_string())
+            })?;
+
+            Some((char, end + 2))
+        });
+
+        match decoded {
+            Some((char, length)) => {
+                output.push_str(&char);
+                rest = &rest[length..];
+            },
+            None => {
+                output.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+    output.push_str(rest);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_plain_text() {
+        assert_eq!(to_text("  a < b && c\nnext line \n"), "a < b && c\nnext line");
+        assert!(!is_html("1 < 2"));
+    }
+
+    #[test]
+    fn converts_markup_to_lines() {
+        let html = "<p>Agenda:</p><ul><li>Intro &amp; news</li><li><b>Q&amp;A</b></li></ul>Line<br>break&nbsp;here&#33;";
+
+        assert_eq!(to_text(html), "Agenda:\n- Intro & news\n- Q&A\nLine\nbreak here!");
+    }
+
+    #[test]
+    fn appends_link_targets() {
+        let html = "See <a href=\"https://example.com/doc?a=1&amp;b=2\">the doc</a> or <a href='https://example.com'>https://example.com</a>";
+
+        assert_eq!(to_text(html), "See the doc (https://example.com/doc?a=1&b=2) or https://example.com");
+    }
+
+    #[test]
+    fn collapses_blank_lines() {
+        assert_eq!(to_text("<div>a</div><br><br><br><div>b</div>"), "a\n\nb");
+    }
+}
@@ -0,0 +1,27 @@
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+pub struct Interval {
+    period: Option<Duration>,
+    last: Option<Instant>,
+}
+
+impl Interval {
+    pub fn from_secs(secs: u64) -> Self {
+        Self {
+            period: (secs > 0).then(|| Duration::from_secs(secs)),
+            last: None,
+        }
+    }
+
+    pub fn is_due(&self) -> bool {
+        match (self.period, self.last) {
+            (Some(period), Some(last)) => last.elapsed() >= period,
+            _ => false,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.last = Some(Instant::now());
+    }
+}
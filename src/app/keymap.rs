@@ -0,0 +1,488 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+
+use crate::app::config::{KeymapConfig, Preset};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Quit,
+    Help,
+    FocusBookmark,
+    FocusTab,
+    NextTab,
+    NextField,
+    Up,
+    Down,
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Open,
+    OpenBrowser,
+    Add,
+    Edit,
+    Submit,
+    Delete,
+    Complete,
+    Search,
+    Refresh,
+    PrevDay,
+    NextDay,
+    PrevWeek,
+    NextWeek,
+    Today,
+    GoToDate,
+    ToggleView,
+    Calendars,
+    Rsvp,
+    Invitations,
+    FindTime,
+    PickDate,
+    Messages,
+    Accounts,
+    Logout,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Context {
+    List,
+    Form,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let modifiers = modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+
+        match code {
+            KeyCode::Char(char) if modifiers.contains(KeyModifiers::SHIFT) => Self {
+                code: KeyCode::Char(char.to_ascii_uppercase()),
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            KeyCode::BackTab => Self {
+                code: KeyCode::BackTab,
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            _ => Self { code, modifiers },
+        }
+    }
+}
+
+impl From<KeyEvent> for KeyChord {
+    fn from(key: KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts: Vec<&str> = s.split('+').collect();
+        let key = match parts.pop() {
+            Some("") if s.ends_with("++") || s == "+" => {
+                parts.pop();
+                "+"
+            },
+            Some(key) if !key.is_empty() => key,
+            _ => return Err(format!("invalid key `{}`", s)),
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier in parts {
+            modifiers |= match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(format!("unknown modifier `{}` in `{}`", modifier, s)),
+            };
+        }
+
+        let code = match key.to_ascii_lowercase().as_str() {
+            "esc" | "escape" => KeyCode::Esc,
+            "enter" | "return" => KeyCode::Enter,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" | "ins" => KeyCode::Insert,
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            lower => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(char), None) => KeyCode::Char(char),
+                    _ => match lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                        Some(n) if (1..=24).contains(&n) => KeyCode::F(n),
+                        _ => return Err(format!("unknown key `{}` in `{}`", key, s)),
+                    },
+                }
+            },
+        };
+
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(char) if char.is_ascii_uppercase() => write!(f, "Shift+{}", char),
+            KeyCode::Char(char) => write!(f, "{}", char),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            KeyCode::BackTab => write!(f, "Shift+Tab"),
+            KeyCode::PageUp => write!(f, "PageUp"),
+            KeyCode::PageDown => write!(f, "PageDown"),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct KeySequence(Vec<KeyChord>);
+
+impl FromStr for KeySequence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chords = s
+            .split_whitespace()
+            .map(KeyChord::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if chords.is_empty() {
+            return Err("empty key binding".to_string());
+        }
+
+        Ok(Self(chords))
+    }
+}
+
+impl TryFrom<String> for KeySequence {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let chords: Vec<String> = self.0.iter().map(|chord| chord.to_string()).collect();
+
+        write!(f, "{}", chords.join(" "))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Resolved {
+    Action(Action),
+    Pending,
+    Unbound,
+}
+
+#[derive(Debug)]
+pub struct Keymap {
+    bindings: HashMap<Context, Vec<(KeySequence, Action)>>,
+    pending: Vec<KeyChord>,
+    pending_context: Context,
+}
+
+impl Keymap {
+    pub fn new(config: &KeymapConfig) -> Self {
+        let mut keymap = match config.preset {
+            Preset::Default => Self::default_preset(),
+            Preset::Vim => Self::vim_preset(),
+        };
+
+        keymap.apply(Context::List, &config.list);
+        keymap.apply(Context::Form, &config.form);
+
+        keymap
+    }
+
+    fn default_preset() -> Self {
+        Self::preset(
+            &[
+                (Action::Quit, &["esc"]),
+                (Action::Help, &["F1"]),
+                (Action::FocusBookmark, &["F5"]),
+                (Action::FocusTab, &["F6"]),
+                (Action::NextTab, &["tab"]),
+                (Action::Up, &["up"]),
+                (Action::Down, &["down"]),
+                (Action::Left, &["left"]),
+                (Action::Right, &["right"]),
+                (Action::Top, &["home"]),
+                (Action::Bottom, &["end"]),
+                (Action::Open, &["enter"]),
+                (Action::OpenBrowser, &["o"]),
+                (Action::Add, &["F2"]),
+                (Action::Edit, &["F3"]),
+                (Action::Delete, &["shift+d"]),
+                (Action::Complete, &["shift+c"]),
+                (Action::Search, &["/"]),
+                (Action::Refresh, &["ctrl+r"]),
+                (Action::PrevDay, &["["]),
+                (Action::NextDay, &["]"]),
+                (Action::PrevWeek, &["{"]),
+                (Action::NextWeek, &["}"]),
+                (Action::Today, &["t"]),
+                (Action::GoToDate, &["ctrl+g"]),
+                (Action::ToggleView, &["v"]),
+                (Action::Calendars, &["F4"]),
+                (Action::Rsvp, &["r"]),
+                (Action::Invitations, &["i"]),
+                (Action::FindTime, &["f"]),
+                (Action::Messages, &["F9"]),
+                (Action::Accounts, &["F7"]),
+                (Action::Logout, &["F8"]),
+            ],
+            &[
+                (Action::Quit, &["esc"]),
+                (Action::FocusBookmark, &["F5"]),
+                (Action::FocusTab, &["F6"]),
+                (Action::NextField, &["tab"]),
+                (Action::Submit, &["F12"]),
+                (Action::PickDate, &["F4"]),
+            ],
+        )
+    }
+
+    fn vim_preset() -> Self {
+        Self::preset(
+            &[
+                (Action::Quit, &["esc", "q"]),
+                (Action::Help, &["?", "F1"]),
+                (Action::FocusBookmark, &["ctrl+w h", "F5"]),
+                (Action::FocusTab, &["ctrl+w l", "F6"]),
+                (Action::NextTab, &["tab", "g t"]),
+                (Action::Up, &["k", "up"]),
+                (Action::Down, &["j", "down"]),
+                (Action::Left, &["left"]),
+                (Action::Right, &["right"]),
+                (Action::Top, &["g g", "home"]),
+                (Action::Bottom, &["shift+g", "end"]),
+                (Action::Open, &["enter", "o"]),
+                (Action::OpenBrowser, &["g x"]),
+                (Action::Add, &["a"]),
+                (Action::Edit, &["e"]),
+                (Action::Delete, &["d d"]),
+                (Action::Complete, &["x"]),
+                (Action::Search, &["/"]),
+                (Action::Refresh, &["r", "ctrl+r"]),
+                (Action::PrevDay, &["h", "["]),
+                (Action::NextDay, &["l", "]"]),
+                (Action::PrevWeek, &["shift+h", "{"]),
+                (Action::NextWeek, &["shift+l", "}"]),
+                (Action::Today, &["t"]),
+                (Action::GoToDate, &["g d", "ctrl+g"]),
+                (Action::ToggleView, &["v"]),
+                (Action::Calendars, &["g c", "F4"]),
+                (Action::Rsvp, &["g r"]),
+                (Action::Invitations, &["g i"]),
+                (Action::FindTime, &["f"]),
+                (Action::Messages, &["m", "F9"]),
+                (Action::Accounts, &["g a", "F7"]),
+                (Action::Logout, &["F8"]),
+            ],
+            &[
+                (Action::Quit, &["esc"]),
+                (Action::FocusBookmark, &["F5"]),
+                (Action::FocusTab, &["F6"]),
+                (Action::NextField, &["tab"]),
+                (Action::Submit, &["ctrl+s", "F12"]),
+                (Action::PickDate, &["ctrl+d", "F4"]),
+            ],
+        )
+    }
+
+    fn preset(list: &[(Action, &[&str])], form: &[(Action, &[&str])]) -> Self {
+        let bindings = |entries: &[(Action, &[&str])]| -> Vec<(KeySequence, Action)> {
+            entries
+                .iter()
+                .flat_map(|(action, keys)| {
+                    keys.iter().map(|key| (key.parse().expect("Invalid preset key binding"), *action))
+                })
+                .collect()
+        };
+
+        Self {
+            bindings: HashMap::from([
+                (Context::List, bindings(list)),
+                (Context::Form, bindings(form)),
+            ]),
+            pending: Vec::new(),
+            pending_context: Context::List,
+        }
+    }
+
+    fn apply(&mut self, context: Context, overrides: &HashMap<Action, Vec<KeySequence>>) {
+        let bindings = self.bindings.entry(context).or_default();
+
+        for (action, keys) in overrides {
+            bindings.retain(|(_, bound)| bound != action);
+            bindings.extend(keys.iter().map(|key| (key.clone(), *action)));
+        }
+    }
+
+    pub fn resolve(&mut self, context: Context, key: KeyEvent) -> Resolved {
+        if self.pending_context != context {
+            self.pending.clear();
+            self.pending_context = context;
+        }
+
+        self.pending.push(KeyChord::from(key));
+
+        let bindings = &self.bindings[&context];
+        if let Some((_, action)) = bindings.iter().find(|(keys, _)| keys.0 == self.pending) {
+            self.pending.clear();
+            return Resolved::Action(*action);
+        }
+        if bindings.iter().any(|(keys, _)| keys.0.starts_with(&self.pending)) {
+            return Resolved::Pending;
+        }
+
+        let retry = self.pending.len() > 1;
+        self.pending.clear();
+
+        if retry {
+            self.resolve(context, key)
+        } else {
+            Resolved::Unbound
+        }
+    }
+
+    pub fn describe(&self, context: Context, action: Action) -> String {
+        let keys: Vec<String> = self.bindings[&context]
+            .iter()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(keys, _)| keys.to_string())
+            .collect();
+
+        keys.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(text: &str) -> KeyChord {
+        text.parse().unwrap()
+    }
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn char(char: char) -> KeyEvent {
+        key(KeyCode::Char(char), KeyModifiers::NONE)
+    }
+
+    fn keymap(preset: Preset) -> Keymap {
+        Keymap::new(&KeymapConfig { preset, ..KeymapConfig::default() })
+    }
+
+    #[test]
+    fn parses_key_chords() {
+        assert_eq!(chord("ctrl+r"), KeyChord::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        assert_eq!(chord("Control+Alt+Enter"), KeyChord::new(KeyCode::Enter, KeyModifiers::CONTROL | KeyModifiers::ALT));
+        assert_eq!(chord("shift+d"), chord("D"));
+        assert_eq!(chord("ctrl++"), KeyChord::new(KeyCode::Char('+'), KeyModifiers::CONTROL));
+        assert_eq!(chord("F12"), KeyChord::new(KeyCode::F(12), KeyModifiers::NONE));
+        assert_eq!(chord("space"), KeyChord::new(KeyCode::Char(' '), KeyModifiers::NONE));
+
+        for text in ["", "hyper+x", "ctrl+", "f25", "enterr"] {
+            assert!(text.parse::<KeyChord>().is_err(), "{}", text);
+        }
+        assert!("  ".parse::<KeySequence>().is_err());
+    }
+
+    #[test]
+    fn normalizes_key_events() {
+        assert_eq!(KeyChord::from(key(KeyCode::Char('G'), KeyModifiers::SHIFT)), chord("shift+g"));
+        assert_eq!(KeyChord::from(key(KeyCode::BackTab, KeyModifiers::SHIFT)), chord("backtab"));
+    }
+
+    #[test]
+    fn displays_key_sequences() {
+        let displayed = |text: &str| text.parse::<KeySequence>().unwrap().to_string();
+
+        assert_eq!(displayed("ctrl+s"), "Ctrl+s");
+        assert_eq!(displayed("shift+g"), "Shift+G");
+        assert_eq!(displayed("ctrl+w  h"), "Ctrl+w h");
+        assert_eq!(displayed("space"), "Space");
+        assert_eq!(displayed("backtab"), "Shift+Tab");
+        assert_eq!(displayed("pagedown"), "PageDown");
+    }
+
+    #[test]
+    fn presets_have_no_conflicting_bindings() {
+        for keymap in [Keymap::default_preset(), Keymap::vim_preset()] {
+            for (context, bindings) in &keymap.bindings {
+                for (i, (keys, action)) in bindings.iter().enumerate() {
+                    for (other, other_action) in &bindings[i + 1..] {
+                        assert!(
+                            !keys.0.starts_with(&other.0) && !other.0.starts_with(&keys.0),
+                            "{:?}: `{}` ({:?}) conflicts with `{}` ({:?})", context, keys, action, other, other_action,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn resolves_multi_key_sequences() {
+        let mut keymap = keymap(Preset::Vim);
+
+        assert_eq!(keymap.resolve(Context::List, char('g')), Resolved::Pending);
+        assert_eq!(keymap.resolve(Context::List, char('g')), Resolved::Action(Action::Top));
+
+        assert_eq!(keymap.resolve(Context::List, char('g')), Resolved::Pending);
+        assert_eq!(keymap.resolve(Context::List, char('j')), Resolved::Action(Action::Down));
+
+        assert_eq!(keymap.resolve(Context::List, char('d')), Resolved::Pending);
+        assert_eq!(keymap.resolve(Context::Form, char('d')), Resolved::Unbound);
+        assert_eq!(keymap.resolve(Context::List, char('d')), Resolved::Pending);
+    }
+
+    #[test]
+    fn applies_overrides_per_context() {
+        let config = KeymapConfig {
+            list: HashMap::from([(Action::Add, vec!["ctrl+n".parse().unwrap()])]),
+            ..KeymapConfig::default()
+        };
+        let mut keymap = Keymap::new(&config);
+
+        assert_eq!(keymap.describe(Context::List, Action::Add), "Ctrl+n");
+        assert_eq!(keymap.resolve(Context::List, key(KeyCode::F(2), KeyModifiers::NONE)), Resolved::Unbound);
+        assert_eq!(keymap.describe(Context::Form, Action::Submit), "F12");
+        assert_eq!(keymap.describe(Context::List, Action::Help), "F1");
+    }
+}
@@ -1,7 +1,15 @@
 pub mod app;
 pub mod bookmark;
+pub mod config;
+pub mod date_parser;
 pub mod google;
+pub mod html;
+pub mod interval;
+pub mod keymap;
+pub mod notification;
 pub mod schedule;
+pub mod tab;
 pub mod task;
 pub mod ui;
-pub mod virtualbox;
\ No newline at end of file
+pub mod virtualbox;
+pub mod worker;
\ No newline at end of file
@@ -0,0 +1,45 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use chrono::{DateTime, Local};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Level {
+    Info,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub level: Level,
+    pub message: String,
+    pub time: DateTime<Local>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Notifier {
+    sender: Sender<Notification>,
+}
+
+impl Notifier {
+    pub fn new() -> (Self, Receiver<Notification>) {
+        let (sender, receiver) = mpsc::channel();
+
+        (Self { sender }, receiver)
+    }
+
+    pub fn info(&self, message: impl Into<String>) {
+        self.send(Level::Info, message.into());
+    }
+
+    pub fn error(&self, message: impl Into<String>) {
+        self.send(Level::Error, message.into());
+    }
+
+    fn send(&self, level: Level, message: String) {
+        let _ = self.sender.send(Notification {
+            level,
+            message,
+            time: Local::now(),
+        });
+    }
+}
@@ -0,0 +1,53 @@
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::Margin,
+    Frame,
+};
+
+use crate::app::ui::{
+    checkbox::{CheckBox, CheckBoxes},
+    popup::Popup,
+};
+
+#[derive(Debug)]
+pub struct CalendarPicker {
+    pub popup: Popup,
+    pub checkboxes: CheckBoxes,
+}
+
+impl CalendarPicker {
+    pub fn new() -> Self {
+        Self {
+            popup: Popup::new("Calendars"),
+            checkboxes: CheckBoxes::new(),
+        }
+    }
+
+    pub fn open(&mut self, checkboxes: Vec<CheckBox>) {
+        self.popup.active = true;
+        self.checkboxes.clear();
+        self.checkboxes.set(checkboxes);
+    }
+
+    pub fn checked(&self) -> Vec<bool> {
+        self.checkboxes.checkboxes
+            .iter()
+            .map(|checkbox| checkbox.is_checked())
+            .collect()
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        if self.popup.active {
+            let area = self.popup.render(frame, [50, 60]);
+
+            self.checkboxes.render_checked(frame, area.inner(Margin::new(2, 1)), true, true);
+        }
+    }
+
+    pub fn key_binding(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char(' ') | KeyCode::Enter => self.checkboxes.toggle(),
+            _ => self.checkboxes.key_binding(key),
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use chrono::{Duration, NaiveDate};
+use chrono_tz::Tz;
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::Margin,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::google::calendar::{Attendee, Event, Person};
+use crate::app::html;
+use crate::app::ui::popup::Popup;
+
+const LABEL_WIDTH: usize = 12;
+const DAY_FORMAT: &str = "%a %Y-%m-%d";
+const TIME_FORMAT: &str = "%H:%M";
+
+#[derive(Debug)]
+pub struct Detail {
+    pub popup: Popup,
+    lines: Vec<Line<'static>>,
+    scroll: usize,
+    height: usize,
+}
+
+impl Detail {
+    pub fn new() -> Self {
+        Self {
+            popup: Popup::new("Schedule"),
+            lines: Vec::new(),
+            scroll: 0,
+            height: 0,
+        }
+    }
+
+    pub fn open(&mut self, event: &Event, calendar: &str, time_zone: Tz) {
+        self.popup.title = event.summary.clone().unwrap_or_else(|| "(No title)".to_string());
+        self.popup.active = true;
+        self.lines = lines(event, calendar, time_zone);
+        self.scroll = 0;
+    }
+
+    pub fn scroll(&mut self, lines: isize) {
+        self.scroll = self.scroll.saturating_add_signed(lines);
+    }
+
+    pub fn top(&mut self) {
+        self.scroll = 0;
+    }
+
+    pub fn bottom(&mut self) {
+        self.scroll = usize::MAX;
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        if self.popup.active {
+            let area = self.popup.render(frame, [70, 80]).inner(Margin::new(2, 1));
+
+            let text = Paragraph::new(self.lines.clone()).wrap(Wrap { trim: false });
+            let max_scroll = text.line_count(area.width).saturating_sub(area.height as usize);
+            self.scroll = self.scroll.min(max_scroll);
+            self.height = area.height as usize;
+
+            frame.render_widget(text.scroll((self.scroll as u16, 0)), area);
+        }
+    }
+
+    pub fn key_binding(&mut self, key: KeyEvent) {
+        let page = self.height.max(1) as isize;
+
+        match key.code {
+            KeyCode::PageUp => self.scroll(-page),
+            KeyCode::PageDown => self.scroll(page),
+            _ => {},
+        }
+    }
+}
+
+fn lines(event: &Event, calendar: &str, time_zone: Tz) -> Vec<Line<'static>> {
+    let mut lines = vec![field("When", when(event, time_zone)), field("Calendar", calendar.to_string())];
+
+    if event.recurring_event_id.is_some() {
+        lines.push(field("Repeats", "Part of a recurring series".to_string()));
+    }
+    if let Some(location) = event.location.as_deref().filter(|location| !location.is_empty()) {
+        lines.push(field("Location", location.to_string()));
+    }
+    if let Some(organizer) = &event.organizer {
+        lines.push(field("Organizer", person(organizer)));
+    }
+
+    let conference = event.conference_data.as_ref();
+    let meet = event.hangout_link.as_deref().or_else(|| conference.and_then(|conference| conference.video_uri()));
+    if let Some(meet) = meet {
+        let name = conference
+            .and_then(|conference| conference.conference_solution.as_ref())
+            .map_or("Meet", |solution| solution.name.as_str());
+        lines.push(field("Conference", format!("{} {}", name, meet)));
+    }
+    for entry_point in conference.iter().flat_map(|conference| &conference.entry_points) {
+        if entry_point.entry_point_type != "video" {
+            let label = entry_point.label.as_deref().unwrap_or(&entry_point.uri);
+            lines.push(field(&capitalize(&entry_point.entry_point_type), label.to_string()));
+        }
+    }
+
+    if !event.attendees.is_empty() {
+        lines.push(Line::default());
+        lines.push(heading(format!("Attendees ({})", event.attendees.len())));
+        lines.extend(event.attendees.iter().map(attendee));
+    }
+
+    if !event.attachments.is_empty() {
+        lines.push(Line::default());
+        lines.push(heading("Attachments".to_string()));
+        lines.extend(event.attachments.iter().map(|attachment| {
+            let title = attachment.title.as_deref().unwrap_or("(Untitled)");
+            Line::from(format!("  {}  {}", title, attachment.file_url))
+        }));
+    }
+
+    let description = html::to_text(event.description.as_deref().unwrap_or_default());
+    if !description.is_empty() {
+        lines.push(Line::default());
+        lines.push(heading("Description".to_string()));
+        lines.extend(description.lines().map(|line| Line::from(line.to_string())));
+    }
+
+    lines
+}
+
+fn format_duration(duration: Duration) -> String {
+    let parts: Vec<String> = [
+        (duration.num_days(), "d"),
+        (duration.num_hours() % 24, "h"),
+        (duration.num_minutes() % 60, "m"),
+    ]
+    .iter()
+    .filter(|(value, _)| *value > 0)
+    .map(|(value, unit)| format!("{}{}", value, unit))
+    .collect();
+
+    if parts.is_empty() {
+        "0m".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+fn when(event: &Event, time_zone: Tz) -> String {
+    if let (None, Some(start)) = (event.start.date_time, event.start.date) {
+        let end = event.end.date.filter(|end| *end > start).unwrap_or(start + Duration::days(1));
+        return all_day(start, end);
+    }
+
+    let (Some(start), Some(end)) = (event.start.in_zone(&time_zone), event.end.in_zone(&time_zone)) else {
+        return String::new();
+    };
+    let duration = format_duration(end - start);
+
+    if start.date_naive() == end.date_naive() {
+        format!("{} {} - {} ({})",
+            start.format(DAY_FORMAT),
+            start.format(TIME_FORMAT),
+            end.format(TIME_FORMAT),
+            duration,
+        )
+    } else {
+        format!("{} {} - {} {} ({})",
+            start.format(DAY_FORMAT),
+            start.format(TIME_FORMAT),
+            end.format(DAY_FORMAT),
+            end.format(TIME_FORMAT),
+            duration,
+        )
+    }
+}
+
+fn all_day(start: NaiveDate, end: NaiveDate) -> String {
+    let days = (end - start).num_days();
+
+    if days <= 1 {
+        format!("{} (all day)", start.format(DAY_FORMAT))
+    } else {
+        format!("{} - {} ({} days)",
+            start.format(DAY_FORMAT),
+            (end - Duration::days(1)).format(DAY_FORMAT),
+            days,
+        )
+    }
+}
+
+fn field(label: &str, value: String) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("{:<width$}", label, width = LABEL_WIDTH), Style::new().fg(Color::DarkGray)),
+        Span::raw(value),
+    ])
+}
+
+fn heading(text: String) -> Line<'static> {
+    Line::styled(text, Style::new().add_modifier(Modifier::BOLD))
+}
+
+fn person(person: &Person) -> String {
+    name_and_email(person.display_name.as_deref(), person.email.as_deref().unwrap_or_default())
+}
+
+fn name_and_email(name: Option<&str>, email: &str) -> String {
+    match name {
+        Some(name) if !email.is_empty() => format!("{} <{}>", name, email),
+        Some(name) => name.to_string(),
+        None => email.to_string(),
+    }
+}
+
+fn attendee(attendee: &Attendee) -> Line<'static> {
+    let (mark, status, color) = match attendee.response_status.as_deref() {
+        Some("accepted") => ("✓", "accepted", Color::Green),
+        Some("declined") => ("✗", "declined", Color::Red),
+        Some("tentative") => ("?", "maybe", Color::Yellow),
+        _ => ("·", "awaiting", Color::DarkGray),
+    };
+
+    let mut notes = vec![status];
+    if attendee.organizer {
+        notes.push("organizer");
+    }
+    if attendee.optional {
+        notes.push("optional");
+    }
+    if attendee.is_self {
+        notes.push("you");
+    }
+
+    Line::from(vec![
+        Span::styled(format!("  {} ", mark), Style::new().fg(color)),
+        Span::raw(name_and_email(attendee.display_name.as_deref(), &attendee.email)),
+        Span::styled(format!("  {}", notes.join(", ")), Style::new().fg(Color::DarkGray)),
+    ])
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono_tz::Asia::Tokyo;
+    use serde_json::json;
+
+    use super::*;
+
+    fn text(lines: &[Line]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(Duration::minutes(90)), "1h 30m");
+        assert_eq!(format_duration(Duration::hours(50)), "2d 2h");
+        assert_eq!(format_duration(Duration::zero()), "0m");
+    }
+
+    #[test]
+    fn lists_event_details() {
+        let event: Event = serde_json::from_value(json!({
+            "summary": "Planning",
+            "start": { "dateTime": "2024-12-02T01:00:00Z" },
+            "end": { "dateTime": "2024-12-02T02:30:00Z" },
+            "location": "Room 1",
+            "organizer": { "email": "boss@example.com", "displayName": "Boss" },
+            "hangoutLink": "https://meet.google.com/abc-defg-hij",
+            "attendees": [
+                { "email": "boss@example.com", "displayName": "Boss", "responseStatus": "accepted", "organizer": true },
+                { "email": "me@example.com", "responseStatus": "tentative", "self": true, "optional": true },
+            ],
+            "attachments": [{ "fileUrl": "https://drive.google.com/file/1", "title": "Agenda" }],
+            "description": "<p>Bring <b>notes</b></p><ul><li>Q1</li></ul>",
+        }))
+        .unwrap();
+
+        assert_eq!(text(&lines(&event, "Work", Tokyo)), [
+            "When        Mon 2024-12-02 10:00 - 11:30 (1h 30m)",
+            "Calendar    Work",
+            "Location    Room 1",
+            "Organizer   Boss <boss@example.com>",
+            "Conference  Meet https://meet.google.com/abc-defg-hij",
+            "",
+            "Attendees (2)",
+            "  ✓ Boss <boss@example.com>  accepted, organizer",
+            "  ? me@example.com  maybe, optional, you",
+            "",
+            "Attachments",
+            "  Agenda  https://drive.google.com/file/1",
+            "",
+            "Description",
+            "Bring notes",
+            "- Q1",
+        ]);
+    }
+
+    #[test]
+    fn describes_multi_day_all_day_events() {
+        let event: Event = serde_json::from_value(json!({
+            "start": { "date": "2024-12-02" },
+            "end": { "date": "2024-12-05" },
+        }))
+        .unwrap();
+
+        assert_eq!(text(&lines(&event, "Holidays", Tokyo))[0], "When        Mon 2024-12-02 - Wed 2024-12-04 (3 days)");
+    }
+}
@@ -0,0 +1,541 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Paragraph, Widget},
+    Frame,
+};
+
+use crate::app::google::calendar::{FreeBusy, PRIMARY};
+use crate::app::ui::{
+    input::Input,
+    pane::Pane,
+    popup::Popup,
+};
+
+pub const WORK_START: u32 = 9;
+pub const WORK_END: u32 = 18;
+
+const ATTENDEES_TITLE: &str = "Attendees (comma separated emails)";
+const DURATION_TITLE: &str = "Duration";
+const FROM_TITLE: &str = "From";
+const TO_TITLE: &str = "To";
+const TIMELINE_TITLE: &str = "Busy";
+const SLOTS_TITLE: &str = "Free Slots";
+const DEFAULT_DURATION: &str = "30m";
+const DEFAULT_DAYS: i64 = 4;
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const DAY_FORMAT: &str = "%a %Y-%m-%d";
+const TIME_FORMAT: &str = "%H:%M";
+const YOU: &str = "You";
+const LABEL_WIDTH: u16 = 24;
+const SLOT_STEP: i64 = 15 * 60;
+const MAX_SLOTS: usize = 20;
+
+pub type Period = (DateTime<Utc>, DateTime<Utc>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Search {
+    pub emails: Vec<String>,
+    pub duration: Duration,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+impl Search {
+    pub fn ids(&self) -> Vec<String> {
+        [PRIMARY.to_string()].into_iter().chain(self.emails.iter().cloned()).collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersonBusy {
+    pub label: String,
+    pub busy: Vec<Period>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Availability {
+    pub people: Vec<PersonBusy>,
+    pub slots: Vec<Period>,
+}
+
+#[derive(Debug)]
+pub struct Finder {
+    pub popup: Popup,
+    pub attendees_pane: Pane,
+    pub attendees: Input,
+    pub duration_pane: Pane,
+    pub duration: Input,
+    pub from_pane: Pane,
+    pub from: Input,
+    pub to_pane: Pane,
+    pub to: Input,
+    pub timeline_pane: Pane,
+    pub slots_pane: Pane,
+    pub search: Option<Search>,
+    pub availability: Availability,
+    pub index: usize,
+    time_zone: Tz,
+}
+
+impl Finder {
+    pub fn new() -> Self {
+        Self {
+            popup: Popup::new("Find a Time"),
+            attendees_pane: Pane::new(ATTENDEES_TITLE),
+            attendees: Input::new(),
+            duration_pane: Pane::new(DURATION_TITLE),
+            duration: Input::new(),
+            from_pane: Pane::new(FROM_TITLE),
+            from: Input::new(),
+            to_pane: Pane::new(TO_TITLE),
+            to: Input::new(),
+            timeline_pane: Pane::new(TIMELINE_TITLE),
+            slots_pane: Pane::new(SLOTS_TITLE),
+            search: None,
+            availability: Availability::default(),
+            index: 0,
+            time_zone: Tz::UTC,
+        }
+    }
+
+    pub fn open(&mut self, today: NaiveDate, time_zone: Tz) {
+        self.popup.active = true;
+        self.time_zone = time_zone;
+        self.attendees.clear();
+        self.duration.set(DEFAULT_DURATION);
+        self.from.set(&today.format(DATE_FORMAT).to_string());
+        self.to.set(&(today + Duration::days(DEFAULT_DAYS)).format(DATE_FORMAT).to_string());
+        self.search = None;
+        self.availability = Availability::default();
+        self.index = 0;
+        self.timeline_pane.set_title(TIMELINE_TITLE);
+        self.slots_pane.set_title(SLOTS_TITLE);
+        self.active_attendees();
+    }
+
+    pub fn set_result(&mut self, search: Search, availability: Availability) {
+        self.slots_pane.set_title(&format!("{} ({})", SLOTS_TITLE, availability.slots.len()));
+        self.search = Some(search);
+        self.availability = availability;
+        self.index = 0;
+        self.set_timeline_title();
+
+        if !self.availability.slots.is_empty() {
+            self.active_slots();
+        }
+    }
+
+    pub fn selected_slot(&self) -> Option<Period> {
+        let duration = self.search.as_ref()?.duration;
+        let (start, _) = self.availability.slots.get(self.index)?;
+
+        Some((*start, *start + duration))
+    }
+
+    fn day(&self) -> Option<NaiveDate> {
+        match self.availability.slots.get(self.index) {
+            Some((start, _)) => Some(start.with_timezone(&self.time_zone).date_naive()),
+            None => self.search.as_ref().map(|search| search.from),
+        }
+    }
+
+    fn set_timeline_title(&mut self) {
+        match self.day() {
+            Some(day) => self.timeline_pane.set_title(&format!("{} on {}", TIMELINE_TITLE, day.format(DAY_FORMAT))),
+            None => self.timeline_pane.set_title(TIMELINE_TITLE),
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        if self.popup.active {
+            let popup_area = self.popup.render(frame, [80, 80]);
+
+            let horizontal = Layout::horizontal([
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ]);
+            let [_, form_area, _] = horizontal.areas(popup_area);
+
+            let layout = Layout::vertical([
+                Constraint::Length(1),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(self.availability.people.len() as u16 + 3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ]);
+            let [_, attendees_area, range_area, timeline_area, slots_area, _] = layout.areas(form_area);
+
+            let attendees_pane = self.attendees_pane.render(frame, attendees_area);
+            self.attendees.render(frame, attendees_pane);
+
+            let [duration_area, from_area, to_area] = Layout::horizontal([
+                Constraint::Length(16),
+                Constraint::Min(0),
+                Constraint::Min(0),
+            ]).areas(range_area);
+
+            let duration_pane = self.duration_pane.render(frame, duration_area);
+            self.duration.render(frame, duration_pane);
+
+            let from_pane = self.from_pane.render(frame, from_area);
+            self.from.render(frame, from_pane);
+
+            let to_pane = self.to_pane.render(frame, to_area);
+            self.to.render(frame, to_pane);
+
+            let timeline_pane = self.timeline_pane.render(frame, timeline_area);
+            if let Some(day) = self.day() {
+                let timeline = Timeline {
+                    people: &self.availability.people,
+                    day,
+                    time_zone: self.time_zone,
+                    slot: self.selected_slot(),
+                };
+                frame.render_widget(timeline, timeline_pane);
+            }
+
+            let slots_pane = self.slots_pane.render(frame, slots_area);
+            frame.render_widget(Paragraph::new(self.slot_lines(slots_pane.height as usize)), slots_pane);
+        }
+    }
+
+    fn slot_lines(&self, height: usize) -> Vec<Line<'_>> {
+        if self.search.is_some() && self.availability.slots.is_empty() {
+            return vec![Line::from("No free slot in working hours")];
+        }
+
+        let skip = (self.index + 1).saturating_sub(height);
+        self.availability.slots
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .map(|(i, (start, end))| {
+                let start = start.with_timezone(&self.time_zone);
+                let end = end.with_timezone(&self.time_zone);
+                let text = format!("{} {} - {}", start.format(DAY_FORMAT), start.format(TIME_FORMAT), end.format(TIME_FORMAT));
+
+                match i == self.index {
+                    true if self.slots_pane.active => Line::styled(text, Style::new().fg(Color::Black).bg(Color::Green)),
+                    true => Line::styled(text, Style::new().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                    false => Line::from(text),
+                }
+            })
+            .collect()
+    }
+
+    pub fn active_attendees(&mut self) {
+        self.attendees_pane.active = true;
+        self.attendees.active = true;
+        self.duration_pane.active = false;
+        self.duration.active = false;
+        self.from_pane.active = false;
+        self.from.active = false;
+        self.to_pane.active = false;
+        self.to.active = false;
+        self.slots_pane.active = false;
+    }
+
+    pub fn active_duration(&mut self) {
+        self.attendees_pane.active = false;
+        self.attendees.active = false;
+        self.duration_pane.active = true;
+        self.duration.active = true;
+        self.from_pane.active = false;
+        self.from.active = false;
+        self.to_pane.active = false;
+        self.to.active = false;
+        self.slots_pane.active = false;
+    }
+
+    pub fn active_from(&mut self) {
+        self.attendees_pane.active = false;
+        self.attendees.active = false;
+        self.duration_pane.active = false;
+        self.duration.active = false;
+        self.from_pane.active = true;
+        self.from.active = true;
+        self.to_pane.active = false;
+        self.to.active = false;
+        self.slots_pane.active = false;
+    }
+
+    pub fn active_to(&mut self) {
+        self.attendees_pane.active = false;
+        self.attendees.active = false;
+        self.duration_pane.active = false;
+        self.duration.active = false;
+        self.from_pane.active = false;
+        self.from.active = false;
+        self.to_pane.active = true;
+        self.to.active = true;
+        self.slots_pane.active = false;
+    }
+
+    pub fn active_slots(&mut self) {
+        self.attendees_pane.active = false;
+        self.attendees.active = false;
+        self.duration_pane.active = false;
+        self.duration.active = false;
+        self.from_pane.active = false;
+        self.from.active = false;
+        self.to_pane.active = false;
+        self.to.active = false;
+        self.slots_pane.active = true;
+    }
+
+    pub fn next(&mut self) {
+        if self.attendees.active {
+            self.active_duration();
+        } else if self.duration.active {
+            self.active_from();
+        } else if self.from.active {
+            self.active_to();
+        } else if self.to.active && !self.availability.slots.is_empty() {
+            self.active_slots();
+        } else {
+            self.active_attendees();
+        }
+    }
+
+    pub fn key_binding(&mut self, key: KeyEvent) {
+        if self.attendees.active {
+            self.attendees.key_binding(key);
+        } else if self.duration.active {
+            self.duration.key_binding(key);
+        } else if self.from.active {
+            self.from.key_binding(key);
+        } else if self.to.active {
+            self.to.key_binding(key);
+        } else if self.slots_pane.active {
+            match key.code {
+                KeyCode::Up => self.index = self.index.saturating_sub(1),
+                KeyCode::Down => self.index = (self.index + 1).min(self.availability.slots.len().saturating_sub(1)),
+                _ => {},
+            }
+            self.set_timeline_title();
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Timeline<'a> {
+    pub people: &'a [PersonBusy],
+    pub day: NaiveDate,
+    pub time_zone: Tz,
+    pub slot: Option<Period>,
+}
+
+impl Widget for Timeline<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let bar_width = area.width.saturating_sub(LABEL_WIDTH);
+        if bar_width == 0 || area.height == 0 {
+            return;
+        }
+
+        let day_start = work_time(self.time_zone, self.day, WORK_START);
+        let span = (work_time(self.time_zone, self.day, WORK_END) - day_start).num_seconds();
+        let cell = |i: u16| day_start + Duration::seconds(span * i64::from(i) / i64::from(bar_width));
+        let overlaps = |(start, end): &Period, i: u16| *start < cell(i + 1) && *end > cell(i);
+
+        let hours = WORK_END - WORK_START;
+        for hour in (0..hours).step_by(3) {
+            let x = area.x + LABEL_WIDTH + (hour * u32::from(bar_width) / hours) as u16;
+            buf.set_string(x, area.y, format!("{:02}", WORK_START + hour), Style::new().fg(Color::DarkGray));
+        }
+
+        for (row, person) in self.people.iter().enumerate().take(usize::from(area.height - 1)) {
+            let y = area.y + 1 + row as u16;
+            let label: String = person.label.chars().take(usize::from(LABEL_WIDTH) - 1).collect();
+            buf.set_string(area.x, y, label, Style::new());
+
+            if let Some(error) = &person.error {
+                buf.set_string(area.x + LABEL_WIDTH, y, format!("unavailable: {}", error), Style::new().fg(Color::Red));
+                continue;
+            }
+
+            for i in 0..bar_width {
+                let (symbol, color) = if person.busy.iter().any(|period| overlaps(period, i)) {
+                    ("█", Color::Red)
+                } else if self.slot.as_ref().is_some_and(|slot| overlaps(slot, i)) {
+                    ("█", Color::Green)
+                } else {
+                    ("·", Color::DarkGray)
+                };
+                buf.set_string(area.x + LABEL_WIDTH + i, y, symbol, Style::new().fg(color));
+            }
+        }
+    }
+}
+
+pub fn availability(search: &Search, free_busy: &FreeBusy, time_zone: Tz, now: DateTime<Utc>) -> Availability {
+    let people: Vec<PersonBusy> = search
+        .ids()
+        .into_iter()
+        .map(|id| {
+            let calendar = free_busy.calendars.get(&id);
+            let error = match calendar {
+                Some(calendar) => calendar.errors.first().map(|error| error.reason.clone()),
+                None => Some("no data".to_string()),
+            };
+
+            PersonBusy {
+                label: if id == PRIMARY { YOU.to_string() } else { id },
+                busy: calendar
+                    .map(|calendar| calendar.busy.iter().map(|period| (period.start, period.end)).collect())
+                    .unwrap_or_default(),
+                error,
+            }
+        })
+        .collect();
+
+    let busy: Vec<Period> = people.iter().flat_map(|person| person.busy.iter().copied()).collect();
+    let slots = free_slots(&busy, search, time_zone, now);
+
+    Availability { people, slots }
+}
+
+pub fn free_slots(busy: &[Period], search: &Search, time_zone: Tz, now: DateTime<Utc>) -> Vec<Period> {
+    let mut busy = busy.to_vec();
+    busy.sort();
+
+    let mut slots = Vec::new();
+    for date in search.from.iter_days().take_while(|date| *date <= search.to) {
+        if matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            continue;
+        }
+
+        let end = work_time(time_zone, date, WORK_END);
+        let mut cursor = round_up(work_time(time_zone, date, WORK_START).max(now));
+        let mut free = Vec::new();
+        for (busy_start, busy_end) in &busy {
+            if *busy_end <= cursor || *busy_start >= end {
+                continue;
+            }
+            if *busy_start > cursor {
+                free.push((cursor, *busy_start));
+            }
+            cursor = cursor.max(*busy_end);
+        }
+        if cursor < end {
+            free.push((cursor, end));
+        }
+
+        slots.extend(free.into_iter().filter(|(start, end)| *end - *start >= search.duration));
+    }
+    slots.truncate(MAX_SLOTS);
+
+    slots
+}
+
+fn work_time(time_zone: Tz, date: NaiveDate, hour: u32) -> DateTime<Utc> {
+    let naive = date.and_hms_opt(hour, 0, 0).unwrap_or_default();
+
+    time_zone
+        .from_local_datetime(&naive)
+        .earliest()
+        .unwrap_or_else(|| time_zone.from_utc_datetime(&naive))
+        .with_timezone(&Utc)
+}
+
+fn round_up(time: DateTime<Utc>) -> DateTime<Utc> {
+    let seconds = time.timestamp();
+    let rest = seconds.rem_euclid(SLOT_STEP);
+    let seconds = if rest == 0 { seconds } else { seconds + SLOT_STEP - rest };
+
+    DateTime::from_timestamp(seconds, 0).unwrap_or(time)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono_tz::Asia::Tokyo;
+    use serde_json::json;
+
+    use super::*;
+
+    fn utc(text: &str) -> DateTime<Utc> {
+        text.parse().unwrap()
+    }
+
+    fn tokyo(text: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(&format!("{}+09:00", text)).unwrap().with_timezone(&Utc)
+    }
+
+    fn search(duration: Duration) -> Search {
+        Search {
+            emails: vec!["a@example.com".to_string()],
+            duration,
+            from: NaiveDate::from_ymd_opt(2024, 12, 6).unwrap(),
+            to: NaiveDate::from_ymd_opt(2024, 12, 9).unwrap(),
+        }
+    }
+
+    #[test]
+    fn finds_gaps_in_working_hours_on_weekdays() {
+        let busy = [
+            (tokyo("2024-12-06T12:00:00"), tokyo("2024-12-06T13:00:00")),
+            (tokyo("2024-12-06T08:00:00"), tokyo("2024-12-06T10:00:00")),
+            (tokyo("2024-12-06T12:30:00"), tokyo("2024-12-06T17:30:00")),
+            (tokyo("2024-12-09T09:00:00"), tokyo("2024-12-09T17:50:00")),
+        ];
+
+        let slots = free_slots(&busy, &search(Duration::minutes(30)), Tokyo, utc("2024-12-06T01:05:00Z"));
+
+        assert_eq!(slots, [
+            (tokyo("2024-12-06T10:15:00"), tokyo("2024-12-06T12:00:00")),
+            (tokyo("2024-12-06T17:30:00"), tokyo("2024-12-06T18:00:00")),
+        ]);
+    }
+
+    #[test]
+    fn merges_everyone_and_reports_unavailable_calendars() {
+        let free_busy: FreeBusy = serde_json::from_value(json!({ "calendars": {
+            "primary": { "busy": [{ "start": "2024-12-06T00:00:00Z", "end": "2024-12-06T08:00:00Z" }] },
+            "a@example.com": { "errors": [{ "reason": "notFound" }] },
+        } }))
+        .unwrap();
+
+        let availability = availability(&search(Duration::hours(2)), &free_busy, Tokyo, utc("2024-12-01T00:00:00Z"));
+
+        assert_eq!(availability.people[0].label, "You");
+        assert_eq!(availability.people[1].error.as_deref(), Some("notFound"));
+        assert_eq!(availability.slots, [(tokyo("2024-12-09T09:00:00"), tokyo("2024-12-09T18:00:00"))]);
+    }
+
+    #[test]
+    fn draws_busy_blocks_and_the_picked_slot() {
+        let people = [PersonBusy {
+            label: "You".to_string(),
+            busy: vec![(tokyo("2024-12-06T09:00:00"), tokyo("2024-12-06T12:00:00"))],
+            error: None,
+        }];
+        let timeline = Timeline {
+            people: &people,
+            day: NaiveDate::from_ymd_opt(2024, 12, 6).unwrap(),
+            time_zone: Tokyo,
+            slot: Some((tokyo("2024-12-06T15:00:00"), tokyo("2024-12-06T18:00:00"))),
+        };
+        let area = Rect::new(0, 0, LABEL_WIDTH + 9, 2);
+        let mut buffer = Buffer::empty(area);
+
+        timeline.render(area, &mut buffer);
+
+        let lines: Vec<String> = (0..area.height)
+            .map(|y| (0..area.width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        assert_eq!(lines, [
+            format!("{:<24}09 12 15 ", ""),
+            format!("{:<24}███···███", "You"),
+        ]);
+        assert_eq!(buffer[(LABEL_WIDTH, 1)].fg, Color::Red);
+        assert_eq!(buffer[(LABEL_WIDTH + 8, 1)].fg, Color::Green);
+    }
+}
@@ -1,10 +1,17 @@
+use chrono::Weekday;
 use ratatui::{
-    crossterm::event::KeyEvent,
-    layout::{Constraint, Layout}, 
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
     Frame,
 };
 
+use crate::app::google::calendar::EVENT_COLORS;
+use crate::app::schedule::recurrence::{Frequency, FREQUENCIES, WEEKDAYS};
 use crate::app::ui::{
+    checkbox::{CheckBox, CheckBoxes},
     pane::Pane,
     popup::Popup,
     input::Input,
@@ -13,7 +20,25 @@ use crate::app::ui::{
 const SUMMARY_TITLE: &str = "Summary";
 const START_TITLE: &str = "Start";
 const END_TITLE: &str = "End";
+const ALL_DAY_TITLE: &str = "All Day";
+const DATE_HINT: &str = "yyyy-mm-dd";
+const CALENDAR_TITLE: &str = "Calendar";
+const ATTENDEES_TITLE: &str = "Attendees (comma separated emails)";
+const LOCATION_TITLE: &str = "Location";
+const MEET_TITLE: &str = "Meet Link";
+const COLOR_TITLE: &str = "Color";
+const VISIBILITY_TITLE: &str = "Visibility";
+const REMINDERS_TITLE: &str = "Reminders (e.g. popup 10m, email 1d; empty for default)";
+const REPEAT_TITLE: &str = "Repeat";
+const WEEKDAYS_TITLE: &str = "Repeat On";
+const ENDS_TITLE: &str = "Ends (yyyy-mm-dd or count, empty for never)";
 const DESCRIPTION_TITLE: &str = "Description";
+const DEFAULT_COLOR: &str = "Default";
+const MORE_ABOVE: &str = "▲ more";
+const MORE_BELOW: &str = "▼ more";
+const ROW_HEIGHT: u16 = 3;
+
+pub const VISIBILITIES: [&str; 4] = ["Default", "Public", "Private", "Confidential"];
 
 #[derive(Debug)]
 pub enum Mode {
@@ -21,18 +46,59 @@ pub enum Mode {
     Edit,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Row {
+    Summary,
+    Start,
+    End,
+    Calendar,
+    Attendees,
+    Location,
+    Options,
+    Reminders,
+    Repeat,
+    Weekdays,
+    Ends,
+    Description,
+}
+
 #[derive(Debug)]
 pub struct Form {
     pub mode: Mode,
     pub popup: Popup,
     pub summary_pane: Pane,
     pub summary: Input,
+    pub all_day_pane: Pane,
+    pub all_day: CheckBox,
     pub start_pane: Pane,
     pub start: Input,
     pub end_pane: Pane,
     pub end: Input,
+    pub calendar_pane: Pane,
+    pub calendar: CheckBoxes,
+    pub calendars: Vec<String>,
+    pub attendees_pane: Pane,
+    pub attendees: Input,
+    pub location_pane: Pane,
+    pub location: Input,
+    pub meet_pane: Pane,
+    pub meet: CheckBox,
+    pub color_pane: Pane,
+    pub color: usize,
+    pub visibility_pane: Pane,
+    pub visibility: CheckBoxes,
+    pub reminders_pane: Pane,
+    pub reminders: Input,
+    pub repeat_pane: Pane,
+    pub repeat: CheckBoxes,
+    pub weekdays_pane: Pane,
+    pub weekdays: CheckBoxes,
+    pub ends_pane: Pane,
+    pub ends: Input,
     pub description_pane: Pane,
     pub description: Input,
+    time_zone: String,
+    scroll: usize,
 }
 
 impl Form {
@@ -42,12 +108,84 @@ impl Form {
             popup: Popup::new(""),
             summary_pane: Pane::new(SUMMARY_TITLE),
             summary: Input::new(),
+            all_day_pane: Pane::new(ALL_DAY_TITLE),
+            all_day: CheckBox::new(""),
             start_pane: Pane::new(START_TITLE),
             start: Input::new(),
             end_pane: Pane::new(END_TITLE),
             end: Input::new(),
+            calendar_pane: Pane::new(CALENDAR_TITLE),
+            calendar: CheckBoxes::new(),
+            calendars: Vec::new(),
+            attendees_pane: Pane::new(ATTENDEES_TITLE),
+            attendees: Input::new(),
+            location_pane: Pane::new(LOCATION_TITLE),
+            location: Input::new(),
+            meet_pane: Pane::new(MEET_TITLE),
+            meet: CheckBox::new(""),
+            color_pane: Pane::new(COLOR_TITLE),
+            color: 0,
+            visibility_pane: Pane::new(VISIBILITY_TITLE),
+            visibility: CheckBoxes::new(),
+            reminders_pane: Pane::new(REMINDERS_TITLE),
+            reminders: Input::new(),
+            repeat_pane: Pane::new(REPEAT_TITLE),
+            repeat: CheckBoxes::new(),
+            weekdays_pane: Pane::new(WEEKDAYS_TITLE),
+            weekdays: CheckBoxes::new(),
+            ends_pane: Pane::new(ENDS_TITLE),
+            ends: Input::new(),
             description_pane: Pane::new(DESCRIPTION_TITLE),
             description: Input::new(),
+            time_zone: String::new(),
+            scroll: 0,
+        }
+    }
+
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = vec![
+            Row::Summary,
+            Row::Start,
+            Row::End,
+            Row::Calendar,
+            Row::Attendees,
+            Row::Location,
+            Row::Options,
+            Row::Reminders,
+        ];
+        if matches!(self.mode, Mode::New) {
+            rows.extend([Row::Repeat, Row::Weekdays, Row::Ends]);
+        }
+        rows.push(Row::Description);
+
+        rows
+    }
+
+    fn active_row(&self) -> Row {
+        if self.all_day_pane.active || self.start.active {
+            Row::Start
+        } else if self.end.active {
+            Row::End
+        } else if self.calendar_pane.active {
+            Row::Calendar
+        } else if self.attendees.active {
+            Row::Attendees
+        } else if self.location.active {
+            Row::Location
+        } else if self.meet_pane.active || self.color_pane.active || self.visibility_pane.active {
+            Row::Options
+        } else if self.reminders.active {
+            Row::Reminders
+        } else if self.repeat_pane.active {
+            Row::Repeat
+        } else if self.weekdays_pane.active {
+            Row::Weekdays
+        } else if self.ends.active {
+            Row::Ends
+        } else if self.description.active {
+            Row::Description
+        } else {
+            Row::Summary
         }
     }
 
@@ -64,89 +202,490 @@ impl Form {
 
             let layout = Layout::vertical([
                 Constraint::Length(1),
-                Constraint::Length(3),
-                Constraint::Length(3),
-                Constraint::Length(3),
                 Constraint::Min(0),
                 Constraint::Length(1),
             ]);
-            let [_, summary_area, start_area, end_area, description_area, _] = 
-                layout.areas(form_area);
+            let [above_area, rows_area, below_area] = layout.areas(form_area);
+
+            let rows = self.rows();
+            let visible = usize::from((rows_area.height / ROW_HEIGHT).max(1));
+            let active = rows.iter().position(|row| *row == self.active_row()).unwrap_or_default();
+            self.scroll = self.scroll.min(active).max((active + 1).saturating_sub(visible));
+
+            for (i, row) in rows.iter().enumerate().skip(self.scroll).take(visible) {
+                let y = rows_area.y + (i - self.scroll) as u16 * ROW_HEIGHT;
+                self.render_row(frame, *row, Rect::new(rows_area.x, y, rows_area.width, ROW_HEIGHT));
+            }
+
+            let style = Style::new().fg(Color::DarkGray);
+            if self.scroll > 0 {
+                frame.render_widget(Paragraph::new(MORE_ABOVE).style(style).right_aligned(), above_area);
+            }
+            if self.scroll + visible < rows.len() {
+                frame.render_widget(Paragraph::new(MORE_BELOW).style(style).right_aligned(), below_area);
+            }
+        }
+    }
+
+    fn render_row(&mut self, frame: &mut Frame, row: Row, area: Rect) {
+        match row {
+            Row::Summary => {
+                let summary_pane = self.summary_pane.render(frame, area);
+                self.summary.render(frame, summary_pane);
+            },
+            Row::Start => {
+                let [start_area, all_day_area] = Layout::horizontal([
+                    Constraint::Min(0),
+                    Constraint::Length(11),
+                ]).areas(area);
+
+                let all_day_pane = self.all_day_pane.render(frame, all_day_area);
+                self.all_day.render(frame, all_day_pane, self.all_day_pane.active);
+
+                let start_pane = self.start_pane.render(frame, start_area);
+                self.start.render(frame, start_pane);
+            },
+            Row::End => {
+                let end_pane = self.end_pane.render(frame, area);
+                self.end.render(frame, end_pane);
+            },
+            Row::Calendar => {
+                let calendar_pane = self.calendar_pane.render(frame, area);
+                let calendars = self.calendars.iter().map(String::as_str).collect();
+                self.calendar.render(frame, calendar_pane, calendars, false);
+            },
+            Row::Attendees => {
+                let attendees_pane = self.attendees_pane.render(frame, area);
+                self.attendees.render(frame, attendees_pane);
+            },
+            Row::Location => {
+                let location_pane = self.location_pane.render(frame, area);
+                self.location.render(frame, location_pane);
+            },
+            Row::Options => {
+                let [meet_area, color_area, visibility_area] = Layout::horizontal([
+                    Constraint::Length(13),
+                    Constraint::Length(20),
+                    Constraint::Min(0),
+                ]).areas(area);
+
+                let meet_pane = self.meet_pane.render(frame, meet_area);
+                self.meet.render(frame, meet_pane, self.meet_pane.active);
+
+                let color_pane = self.color_pane.render(frame, color_area);
+                frame.render_widget(Paragraph::new(self.color_line()), color_pane);
+
+                let visibility_pane = self.visibility_pane.render(frame, visibility_area);
+                self.visibility.render(frame, visibility_pane, VISIBILITIES.to_vec(), false);
+            },
+            Row::Reminders => {
+                let reminders_pane = self.reminders_pane.render(frame, area);
+                self.reminders.render(frame, reminders_pane);
+            },
+            Row::Repeat => {
+                let repeat_pane = self.repeat_pane.render(frame, area);
+                self.repeat.render(frame, repeat_pane, FREQUENCIES.to_vec(), false);
+            },
+            Row::Weekdays => {
+                let weekdays_pane = self.weekdays_pane.render(frame, area);
+                self.weekdays.render_checked(frame, weekdays_pane, false, self.weekdays_pane.active);
+            },
+            Row::Ends => {
+                let ends_pane = self.ends_pane.render(frame, area);
+                self.ends.render(frame, ends_pane);
+            },
+            Row::Description => {
+                let description_pane = self.description_pane.render(frame, area);
+                self.description.render(frame, description_pane);
+            },
+        }
+    }
+
+    fn color_line(&self) -> Line<'static> {
+        let mut spans = vec![Span::raw("‹ ")];
+        match self.color.checked_sub(1).and_then(|index| EVENT_COLORS.get(index)) {
+            Some((name, hex)) => {
+                let color = hex.parse().unwrap_or(Color::Reset);
+                spans.push(Span::styled("■ ", Style::new().fg(color)));
+                spans.push(Span::raw(*name));
+            },
+            None => spans.push(Span::raw(DEFAULT_COLOR)),
+        }
+        spans.push(Span::raw(" ›"));
+
+        Line::from(spans)
+    }
+
+    pub fn set_time_zone(&mut self, time_zone: &str) {
+        self.time_zone = time_zone.to_string();
+        self.set_titles();
+    }
+
+    fn set_titles(&mut self) {
+        let hint = if self.is_all_day() { DATE_HINT } else { &self.time_zone };
+
+        self.start_pane.set_title(&format!("{} ({})", START_TITLE, hint));
+        self.end_pane.set_title(&format!("{} ({})", END_TITLE, hint));
+    }
 
-            let summary_pane = self.summary_pane.render(frame, summary_area);
-            self.summary.render(frame, summary_pane);
+    pub fn is_all_day(&self) -> bool {
+        self.all_day.is_checked()
+    }
 
-            let start_pane = self.start_pane.render(frame, start_area);
-            self.start.render(frame, start_pane);
+    pub fn set_all_day(&mut self, all_day: bool) {
+        if self.is_all_day() != all_day {
+            self.toggle_all_day();
+        }
+    }
 
-            let end_pane = self.end_pane.render(frame, end_area);
-            self.end.render(frame, end_pane);
+    fn toggle_all_day(&mut self) {
+        self.all_day.toggle();
+        self.set_titles();
 
-            let description_pane = self.description_pane.render(frame, description_area);
-            self.description.render(frame, description_pane);
+        if self.is_all_day() {
+            for input in [&mut self.start, &mut self.end] {
+                let date = input.text.split_whitespace().next().unwrap_or_default().to_string();
+                input.set(&date);
+            }
         }
     }
 
+    pub fn wants_meet(&self) -> bool {
+        self.meet.is_checked()
+    }
+
+    pub fn set_meet(&mut self, meet: bool) {
+        if self.wants_meet() != meet {
+            self.meet.toggle();
+        }
+    }
+
+    pub fn color_id(&self) -> Option<String> {
+        (self.color > 0).then(|| self.color.to_string())
+    }
+
+    pub fn set_color_id(&mut self, color_id: Option<&str>) {
+        self.color = color_id
+            .and_then(|id| id.parse().ok())
+            .filter(|index| *index <= EVENT_COLORS.len())
+            .unwrap_or(0);
+    }
+
+    fn cycle_color(&mut self, step: isize) {
+        let count = EVENT_COLORS.len() as isize + 1;
+
+        self.color = (self.color as isize + step).rem_euclid(count) as usize;
+    }
+
+    pub fn visibility_value(&self) -> String {
+        VISIBILITIES[self.visibility.index.min(VISIBILITIES.len() - 1)].to_lowercase()
+    }
+
+    pub fn set_visibility(&mut self, visibility: Option<&str>) {
+        self.visibility.index = VISIBILITIES
+            .iter()
+            .position(|value| Some(value.to_lowercase().as_str()) == visibility)
+            .unwrap_or(0);
+    }
+
     pub fn all_clear(&mut self) {
         self.summary.clear();
+        self.all_day = CheckBox::new("");
+        self.set_titles();
         self.start.clear();
         self.end.clear();
+        self.calendar.clear();
+        self.attendees.clear();
+        self.location.clear();
+        self.meet = CheckBox::new("");
+        self.color = 0;
+        self.visibility.clear();
+        self.reminders.clear();
+        self.repeat.clear();
+        self.weekdays.clear();
+        self.weekdays.set(WEEKDAYS.iter().map(|weekday| CheckBox::new(&weekday.to_string())).collect());
+        self.ends.clear();
         self.description.clear();
+        self.scroll = 0;
     }
 
-    pub fn active_summary(&mut self) {
-        self.summary_pane.active = true;
-        self.summary.active = true;
+    pub fn frequency(&self) -> Frequency {
+        Frequency::from_index(self.repeat.index)
+    }
+
+    pub fn repeat_weekdays(&self) -> Vec<Weekday> {
+        WEEKDAYS
+            .into_iter()
+            .zip(&self.weekdays.checkboxes)
+            .filter(|(_, checkbox)| checkbox.is_checked())
+            .map(|(weekday, _)| weekday)
+            .collect()
+    }
+
+    pub fn attendee_emails(&self) -> Vec<String> {
+        self.attendees.text
+            .split(',')
+            .map(str::trim)
+            .filter(|email| !email.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    fn clear_active(&mut self) {
+        self.summary_pane.active = false;
+        self.summary.active = false;
+        self.all_day_pane.active = false;
         self.start_pane.active = false;
         self.start.active = false;
         self.end_pane.active = false;
         self.end.active = false;
+        self.calendar_pane.active = false;
+        self.attendees_pane.active = false;
+        self.attendees.active = false;
+        self.location_pane.active = false;
+        self.location.active = false;
+        self.meet_pane.active = false;
+        self.color_pane.active = false;
+        self.visibility_pane.active = false;
+        self.reminders_pane.active = false;
+        self.reminders.active = false;
+        self.repeat_pane.active = false;
+        self.weekdays_pane.active = false;
+        self.ends_pane.active = false;
+        self.ends.active = false;
         self.description_pane.active = false;
         self.description.active = false;
     }
 
+    pub fn active_summary(&mut self) {
+        self.clear_active();
+        self.summary_pane.active = true;
+        self.summary.active = true;
+    }
+
+    pub fn active_all_day(&mut self) {
+        self.clear_active();
+        self.all_day_pane.active = true;
+    }
+
     pub fn active_start(&mut self) {
-        self.summary_pane.active = false;
-        self.summary.active = false;
+        self.clear_active();
         self.start_pane.active = true;
         self.start.active = true;
-        self.end_pane.active = false;
-        self.end.active = false;
-        self.description_pane.active = false;
-        self.description.active = false;
     }
 
     pub fn active_end(&mut self) {
-        self.summary_pane.active = false;
-        self.summary.active = false;
-        self.start_pane.active = false;
-        self.start.active = false;
+        self.clear_active();
         self.end_pane.active = true;
         self.end.active = true;
-        self.description_pane.active = false;
-        self.description.active = false;
+    }
+
+    pub fn active_calendar(&mut self) {
+        self.clear_active();
+        self.calendar_pane.active = true;
+    }
+
+    pub fn active_attendees(&mut self) {
+        self.clear_active();
+        self.attendees_pane.active = true;
+        self.attendees.active = true;
+    }
+
+    pub fn active_location(&mut self) {
+        self.clear_active();
+        self.location_pane.active = true;
+        self.location.active = true;
+    }
+
+    pub fn active_meet(&mut self) {
+        self.clear_active();
+        self.meet_pane.active = true;
+    }
+
+    pub fn active_color(&mut self) {
+        self.clear_active();
+        self.color_pane.active = true;
+    }
+
+    pub fn active_visibility(&mut self) {
+        self.clear_active();
+        self.visibility_pane.active = true;
+    }
+
+    pub fn active_reminders(&mut self) {
+        self.clear_active();
+        self.reminders_pane.active = true;
+        self.reminders.active = true;
+    }
+
+    pub fn active_repeat(&mut self) {
+        self.clear_active();
+        self.repeat_pane.active = true;
+    }
+
+    pub fn active_weekdays(&mut self) {
+        self.clear_active();
+        self.weekdays_pane.active = true;
+    }
+
+    pub fn active_ends(&mut self) {
+        self.clear_active();
+        self.ends_pane.active = true;
+        self.ends.active = true;
     }
 
     pub fn active_description(&mut self) {
-        self.summary_pane.active = false;
-        self.summary.active = false;
-        self.start_pane.active = false;
-        self.start.active = false;
-        self.end_pane.active = false;
-        self.end.active = false;
+        self.clear_active();
         self.description_pane.active = true;
         self.description.active = true;
     }
 
+    pub fn next(&mut self) {
+        if self.summary.active {
+            self.active_all_day();
+        } else if self.all_day_pane.active {
+            self.active_start();
+        } else if self.start.active {
+            self.active_end();
+        } else if self.end.active && matches!(self.mode, Mode::New) {
+            self.active_calendar();
+        } else if self.end.active || self.calendar_pane.active {
+            self.active_attendees();
+        } else if self.attendees.active {
+            self.active_location();
+        } else if self.location.active {
+            self.active_meet();
+        } else if self.meet_pane.active {
+            self.active_color();
+        } else if self.color_pane.active {
+            self.active_visibility();
+        } else if self.visibility_pane.active {
+            self.active_reminders();
+        } else if self.reminders.active && matches!(self.mode, Mode::New) {
+            self.active_repeat();
+        } else if self.reminders.active {
+            self.active_description();
+        } else if self.repeat_pane.active && self.frequency() == Frequency::Weekly {
+            self.active_weekdays();
+        } else if self.repeat_pane.active && self.frequency() != Frequency::None {
+            self.active_ends();
+        } else if self.repeat_pane.active || self.ends.active {
+            self.active_description();
+        } else if self.weekdays_pane.active {
+            self.active_ends();
+        } else if self.description.active {
+            self.active_summary();
+        }
+    }
+
     pub fn key_binding(&mut self, key: KeyEvent) {
         if self.summary.active {
             self.summary.key_binding(key);
+        } else if self.all_day_pane.active && key.code == KeyCode::Char(' ') {
+            self.toggle_all_day();
         } else if self.start.active {
             self.start.key_binding(key);
         } else if self.end.active {
             self.end.key_binding(key);
+        } else if self.calendar_pane.active {
+            self.calendar.key_binding(key);
+        } else if self.attendees.active {
+            self.attendees.key_binding(key);
+        } else if self.location.active {
+            self.location.key_binding(key);
+        } else if self.meet_pane.active && key.code == KeyCode::Char(' ') {
+            self.meet.toggle();
+        } else if self.color_pane.active && key.code == KeyCode::Left {
+            self.cycle_color(-1);
+        } else if self.color_pane.active && key.code == KeyCode::Right {
+            self.cycle_color(1);
+        } else if self.visibility_pane.active {
+            self.visibility.key_binding(key);
+        } else if self.reminders.active {
+            self.reminders.key_binding(key);
+        } else if self.repeat_pane.active {
+            self.repeat.key_binding(key);
+        } else if self.weekdays_pane.active && key.code == KeyCode::Char(' ') {
+            self.weekdays.toggle();
+        } else if self.weekdays_pane.active {
+            self.weekdays.key_binding(key);
+        } else if self.ends.active {
+            self.ends.key_binding(key);
         } else if self.description.active {
             self.description.key_binding(key);
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use super::*;
+
+    fn draw(form: &mut Form, height: u16) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(100, height)).unwrap();
+        terminal.draw(|frame| form.render(frame)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    fn shows(lines: &[String], text: &str) -> bool {
+        lines.iter().any(|line| line.contains(text))
+    }
+
+    #[test]
+    fn scrolls_to_keep_the_active_field_visible() {
+        let mut form = Form::new();
+        form.popup.active = true;
+        form.all_clear();
+        form.active_summary();
+
+        let lines = draw(&mut form, 20);
+        assert!(shows(&lines, SUMMARY_TITLE));
+        assert!(!shows(&lines, DESCRIPTION_TITLE));
+        assert!(shows(&lines, MORE_BELOW) && !shows(&lines, MORE_ABOVE));
+
+        form.active_description();
+        let lines = draw(&mut form, 20);
+        assert!(!shows(&lines, SUMMARY_TITLE));
+        assert!(shows(&lines, DESCRIPTION_TITLE));
+        assert!(shows(&lines, MORE_ABOVE) && !shows(&lines, MORE_BELOW));
+    }
+
+    #[test]
+    fn fits_without_scrolling_when_tall_enough() {
+        let mut form = Form::new();
+        form.popup.active = true;
+        form.mode = Mode::Edit;
+        form.all_clear();
+        form.active_description();
+
+        let lines = draw(&mut form, 60);
+        assert!(shows(&lines, SUMMARY_TITLE) && shows(&lines, DESCRIPTION_TITLE));
+        assert!(!shows(&lines, MORE_ABOVE) && !shows(&lines, MORE_BELOW));
+        assert!(!shows(&lines, REPEAT_TITLE));
+    }
+
+    #[test]
+    fn cycles_colors_and_maps_visibility() {
+        let mut form = Form::new();
+        form.all_clear();
+        form.active_color();
+
+        form.key_binding(KeyEvent::from(KeyCode::Left));
+        assert_eq!(form.color_id().as_deref(), Some("11"));
+        form.key_binding(KeyEvent::from(KeyCode::Right));
+        assert_eq!(form.color_id(), None);
+
+        form.set_visibility(Some("private"));
+        assert_eq!(form.visibility_value(), "private");
+        form.set_visibility(Some("confidential"));
+        assert_eq!(form.visibility_value(), "confidential");
+        form.set_visibility(None);
+        assert_eq!(form.visibility_value(), "default");
+    }
+}
@@ -0,0 +1,64 @@
+use ratatui::{
+    crossterm::event::KeyEvent,
+    layout::{Constraint, Layout},
+    Frame,
+};
+
+use crate::app::ui::{
+    pane::Pane,
+    popup::Popup,
+    input::Input,
+};
+
+const DATE_TITLE: &str = "Date";
+
+#[derive(Debug)]
+pub struct Jump {
+    pub popup: Popup,
+    pub date_pane: Pane,
+    pub date: Input,
+}
+
+impl Jump {
+    pub fn new() -> Self {
+        Self {
+            popup: Popup::new("Go To Date"),
+            date_pane: Pane::new(DATE_TITLE),
+            date: Input::new(),
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.popup.active = true;
+        self.date.clear();
+        self.date.active = true;
+        self.date_pane.active = true;
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        if self.popup.active {
+            let popup_area = self.popup.render(frame, [40, 30]);
+
+            let horizontal = Layout::horizontal([
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ]);
+            let [_, form_area, _] = horizontal.areas(popup_area);
+
+            let layout = Layout::vertical([
+                Constraint::Length(1),
+                Constraint::Length(3),
+                Constraint::Min(0),
+            ]);
+            let [_, date_area, _] = layout.areas(form_area);
+
+            let date_pane = self.date_pane.render(frame, date_area);
+            self.date.render(frame, date_pane);
+        }
+    }
+
+    pub fn key_binding(&mut self, key: KeyEvent) {
+        self.date.key_binding(key);
+    }
+}
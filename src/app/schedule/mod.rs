@@ -1,2 +1,10 @@
 pub mod schedule;
-pub mod form;
\ No newline at end of file
+pub mod calendars;
+pub mod detail;
+pub mod finder;
+pub mod form;
+pub mod jump;
+pub mod recurrence;
+pub mod reminder;
+pub mod rsvp;
+pub mod scope;
\ No newline at end of file
@@ -0,0 +1,244 @@
+use chrono::{Duration, NaiveDate, Utc, Weekday};
+use chrono_tz::Tz;
+
+use crate::app::google::calendar::EventTime;
+
+pub const FREQUENCIES: [&str; 4] = ["None", "Daily", "Weekly", "Monthly"];
+pub const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+pub const SCOPES: [&str; 3] = ["This event", "This and following events", "All events"];
+
+const RRULE: &str = "RRULE:";
+const UNTIL_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+const UNTIL_DATE_FORMAT: &str = "%Y%m%d";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Frequency {
+    None,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl Frequency {
+    pub fn from_index(index: usize) -> Self {
+        match index {
+            1 => Self::Daily,
+            2 => Self::Weekly,
+            3 => Self::Monthly,
+            _ => Self::None,
+        }
+    }
+
+    fn name(&self) -> Option<&str> {
+        match self {
+            Self::None => None,
+            Self::Daily => Some("DAILY"),
+            Self::Weekly => Some("WEEKLY"),
+            Self::Monthly => Some("MONTHLY"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RepeatEnd {
+    Never,
+    Until(NaiveDate),
+    Count(u32),
+}
+
+impl RepeatEnd {
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+
+        if text.is_empty() {
+            Some(Self::Never)
+        } else if let Ok(count) = text.parse::<u32>() {
+            (count > 0).then_some(Self::Count(count))
+        } else {
+            NaiveDate::parse_from_str(text, "%Y-%m-%d").ok().map(Self::Until)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scope {
+    This,
+    Following,
+    All,
+}
+
+impl Scope {
+    pub fn from_index(index: usize) -> Self {
+        match index {
+            1 => Self::Following,
+            2 => Self::All,
+            _ => Self::This,
+        }
+    }
+}
+
+pub fn rule(frequency: Frequency, weekdays: &[Weekday], end: &RepeatEnd, time_zone: Option<Tz>) -> Option<String> {
+    let mut parts = vec![format!("FREQ={}", frequency.name()?)];
+
+    if frequency == Frequency::Weekly && !weekdays.is_empty() {
+        let days: Vec<&str> = weekdays.iter().map(|weekday| day_name(*weekday)).collect();
+        parts.push(format!("BYDAY={}", days.join(",")));
+    }
+
+    match end {
+        RepeatEnd::Never => {},
+        RepeatEnd::Count(count) => parts.push(format!("COUNT={}", count)),
+        RepeatEnd::Until(date) => match time_zone {
+            Some(time_zone) => {
+                let end_of_day = date.and_hms_opt(23, 59, 59)?
+                    .and_local_timezone(time_zone)
+                    .latest()?
+                    .with_timezone(&Utc);
+                parts.push(format!("UNTIL={}", end_of_day.format(UNTIL_FORMAT)));
+            },
+            None => parts.push(format!("UNTIL={}", date.format(UNTIL_DATE_FORMAT))),
+        },
+    }
+
+    Some(format!("{}{}", RRULE, parts.join(";")))
+}
+
+pub fn end_before(recurrence: &[String], start: &EventTime) -> Vec<String> {
+    let until = match (start.date, start.in_zone(&Utc)) {
+        (Some(date), _) => format!("UNTIL={}", (date - Duration::days(1)).format(UNTIL_DATE_FORMAT)),
+        (None, Some(start)) => format!("UNTIL={}", (start - Duration::seconds(1)).format(UNTIL_FORMAT)),
+        (None, None) => return recurrence.to_vec(),
+    };
+
+    recurrence
+        .iter()
+        .map(|line| match line.strip_prefix(RRULE) {
+            Some(rule) => {
+                let mut parts: Vec<&str> = rule
+                    .split(';')
+                    .filter(|part| !part.starts_with("UNTIL=") && !part.starts_with("COUNT="))
+                    .collect();
+                parts.push(&until);
+                format!("{}{}", RRULE, parts.join(";"))
+            },
+            None => line.clone(),
+        })
+        .collect()
+}
+
+pub fn count(recurrence: &[String]) -> Option<u32> {
+    recurrence
+        .iter()
+        .filter_map(|line| line.strip_prefix(RRULE))
+        .flat_map(|rule| rule.split(';'))
+        .find_map(|part| part.strip_prefix("COUNT=")?.parse().ok())
+}
+
+pub fn remaining(recurrence: &[String], kept: u32) -> Vec<String> {
+    recurrence
+        .iter()
+        .map(|line| match line.strip_prefix(RRULE) {
+            Some(rule) => {
+                let parts: Vec<String> = rule
+                    .split(';')
+                    .map(|part| match part.strip_prefix("COUNT=").and_then(|count| count.parse::<u32>().ok()) {
+                        Some(count) => format!("COUNT={}", count.saturating_sub(kept).max(1)),
+                        None => part.to_string(),
+                    })
+                    .collect();
+                format!("{}{}", RRULE, parts.join(";"))
+            },
+            None => line.clone(),
+        })
+        .collect()
+}
+
+fn day_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use chrono_tz::Asia::Tokyo;
+
+    use super::*;
+
+    fn date(text: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+    }
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_repeat_ends() {
+        assert_eq!(RepeatEnd::parse(""), Some(RepeatEnd::Never));
+        assert_eq!(RepeatEnd::parse(" 5 "), Some(RepeatEnd::Count(5)));
+        assert_eq!(RepeatEnd::parse("2024-12-31"), Some(RepeatEnd::Until(date("2024-12-31"))));
+        assert_eq!(RepeatEnd::parse("0"), None);
+        assert_eq!(RepeatEnd::parse("soon"), None);
+    }
+
+    #[test]
+    fn builds_rules() {
+        let weekdays = [Weekday::Mon, Weekday::Wed];
+
+        assert_eq!(rule(Frequency::None, &weekdays, &RepeatEnd::Never, None), None);
+        assert_eq!(rule(Frequency::Daily, &weekdays, &RepeatEnd::Never, None).as_deref(), Some("RRULE:FREQ=DAILY"));
+        assert_eq!(
+            rule(Frequency::Weekly, &weekdays, &RepeatEnd::Count(10), None).as_deref(),
+            Some("RRULE:FREQ=WEEKLY;BYDAY=MO,WE;COUNT=10"),
+        );
+        assert_eq!(
+            rule(Frequency::Monthly, &[], &RepeatEnd::Until(date("2024-12-31")), Some(Tokyo)).as_deref(),
+            Some("RRULE:FREQ=MONTHLY;UNTIL=20241231T145959Z"),
+        );
+        assert_eq!(
+            rule(Frequency::Daily, &[], &RepeatEnd::Until(date("2024-12-31")), None).as_deref(),
+            Some("RRULE:FREQ=DAILY;UNTIL=20241231"),
+        );
+    }
+
+    #[test]
+    fn ends_series_before_a_start() {
+        let recurrence = lines(&["EXDATE:20241203T000000Z", "RRULE:FREQ=DAILY;COUNT=10"]);
+        let start: DateTime<Utc> = "2024-12-05T09:00:00Z".parse().unwrap();
+
+        assert_eq!(
+            end_before(&recurrence, &EventTime::at(&start, "UTC")),
+            lines(&["EXDATE:20241203T000000Z", "RRULE:FREQ=DAILY;UNTIL=20241205T085959Z"]),
+        );
+        assert_eq!(
+            end_before(&recurrence, &EventTime { date: Some(date("2024-12-05")), ..EventTime::default() }),
+            lines(&["EXDATE:20241203T000000Z", "RRULE:FREQ=DAILY;UNTIL=20241204"]),
+        );
+    }
+
+    #[test]
+    fn reduces_counts_by_kept_occurrences() {
+        let recurrence = lines(&["RRULE:FREQ=WEEKLY;COUNT=10;BYDAY=MO"]);
+
+        assert_eq!(count(&recurrence), Some(10));
+        assert_eq!(count(&lines(&["RRULE:FREQ=DAILY"])), None);
+        assert_eq!(remaining(&recurrence, 3), lines(&["RRULE:FREQ=WEEKLY;COUNT=7;BYDAY=MO"]));
+        assert_eq!(remaining(&recurrence, 12), lines(&["RRULE:FREQ=WEEKLY;COUNT=1;BYDAY=MO"]));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::app::date_parser;
+use crate::app::google::calendar::ReminderOverride;
+
+pub const MAX_REMINDERS: usize = 5;
+
+const METHODS: [&str; 2] = ["popup", "email"];
+const MAX_MINUTES: i64 = 4 * 7 * 24 * 60;
+
+pub fn parse(text: &str) -> Option<Vec<ReminderOverride>> {
+    let mut overrides = Vec::new();
+
+    for part in text.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+        let lower = part.to_lowercase();
+        let (method, duration) = match lower.split_once(char::is_whitespace) {
+            Some((method, duration)) if METHODS.contains(&method) => (method, duration),
+            _ => (METHODS[0], lower.as_str()),
+        };
+
+        let minutes = date_parser::parse_duration(duration)?.num_minutes();
+        if !(0..=MAX_MINUTES).contains(&minutes) {
+            return None;
+        }
+
+        overrides.push(ReminderOverride {
+            method: method.to_string(),
+            minutes: minutes as u32,
+        });
+    }
+
+    (overrides.len() <= MAX_REMINDERS).then_some(overrides)
+}
+
+pub fn format(overrides: &[ReminderOverride]) -> String {
+    overrides
+        .iter()
+        .map(|reminder| format!("{} {}", reminder.method, format_minutes(reminder.minutes)))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn format_minutes(minutes: u32) -> String {
+    let parts: Vec<String> = [(minutes / (24 * 60), "d"), (minutes / 60 % 24, "h"), (minutes % 60, "m")]
+        .iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect();
+
+    if parts.is_empty() {
+        "0m".to_string()
+    } else {
+        parts.concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reminder(method: &str, minutes: u32) -> ReminderOverride {
+        ReminderOverride {
+            method: method.to_string(),
+            minutes,
+        }
+    }
+
+    #[test]
+    fn parses_methods_and_durations() {
+        assert_eq!(parse("popup 10m, Email 1d, 1h30m"), Some(vec![
+            reminder("popup", 10),
+            reminder("email", 1440),
+            reminder("popup", 90),
+        ]));
+        assert_eq!(parse(""), Some(Vec::new()));
+    }
+
+    #[test]
+    fn rejects_invalid_reminders() {
+        for text in ["sms 10m", "popup soon", "5w", "1m, 2m, 3m, 4m, 5m, 6m"] {
+            assert_eq!(parse(text), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn formats_reminders_back_to_text() {
+        let overrides = [reminder("popup", 0), reminder("email", 1530)];
+
+        assert_eq!(format(&overrides), "popup 0m, email 1d1h30m");
+        assert_eq!(parse(&format(&overrides)), Some(overrides.to_vec()));
+    }
+}
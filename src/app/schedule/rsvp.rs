@@ -0,0 +1,173 @@
+use ratatui::{
+    crossterm::event::KeyEvent,
+    layout::{Constraint, Layout, Margin},
+    Frame,
+};
+use serde_json::{json, Value};
+
+use crate::app::google::calendar::Attendee;
+use crate::app::ui::{
+    checkbox::CheckBoxes,
+    input::Input,
+    pane::Pane,
+    popup::Popup,
+};
+
+pub const RESPONSES: [&str; 3] = ["Yes", "Maybe", "No"];
+pub const NEEDS_ACTION: &str = "needsAction";
+
+const NOTE_TITLE: &str = "Note";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Response {
+    Accepted,
+    Tentative,
+    Declined,
+}
+
+impl Response {
+    pub fn from_index(index: usize) -> Self {
+        match index {
+            1 => Self::Tentative,
+            2 => Self::Declined,
+            _ => Self::Accepted,
+        }
+    }
+
+    fn index(status: Option<&str>) -> usize {
+        match status {
+            Some("tentative") => 1,
+            Some("declined") => 2,
+            _ => 0,
+        }
+    }
+
+    pub fn status(&self) -> &str {
+        match self {
+            Self::Accepted => "accepted",
+            Self::Tentative => "tentative",
+            Self::Declined => "declined",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Rsvp {
+    pub popup: Popup,
+    pub responses: CheckBoxes,
+    pub note_pane: Pane,
+    pub note: Input,
+}
+
+impl Rsvp {
+    pub fn new() -> Self {
+        Self {
+            popup: Popup::new("Reply to Invitation"),
+            responses: CheckBoxes::new(),
+            note_pane: Pane::new(NOTE_TITLE),
+            note: Input::new(),
+        }
+    }
+
+    pub fn open(&mut self, attendee: &Attendee) {
+        self.popup.active = true;
+        self.responses.index = Response::index(attendee.response_status.as_deref());
+        self.note.set(attendee.comment.as_deref().unwrap_or_default());
+        self.note.active = false;
+        self.note_pane.active = false;
+    }
+
+    pub fn close(&mut self) -> (Response, String) {
+        self.popup.active = false;
+
+        (Response::from_index(self.responses.index), self.note.text.trim().to_string())
+    }
+
+    pub fn next(&mut self) {
+        self.note.active = !self.note.active;
+        self.note_pane.active = self.note.active;
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        if self.popup.active {
+            let area = self.popup.render(frame, [50, 30]).inner(Margin::new(2, 1));
+
+            let layout = Layout::vertical([
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(3),
+                Constraint::Min(0),
+            ]);
+            let [responses_area, _, note_area, _] = layout.areas(area);
+
+            self.responses.render(frame, responses_area, RESPONSES.to_vec(), false);
+            let note = self.note_pane.render(frame, note_area);
+            self.note.render(frame, note);
+        }
+    }
+
+    pub fn key_binding(&mut self, key: KeyEvent) {
+        if self.note.active {
+            self.note.key_binding(key);
+        } else {
+            self.responses.key_binding(key);
+        }
+    }
+}
+
+pub fn needs_response(attendee: &Attendee) -> bool {
+    !attendee.organizer && attendee.response_status.as_deref().is_none_or(|status| status == NEEDS_ACTION)
+}
+
+pub fn reply(attendees: &[Attendee], response: Response, note: &str) -> Option<Value> {
+    attendees.iter().find(|attendee| attendee.is_self)?;
+
+    let attendees: Vec<Attendee> = attendees
+        .iter()
+        .map(|attendee| match attendee.is_self {
+            true => Attendee {
+                response_status: Some(response.status().to_string()),
+                comment: (!note.is_empty()).then(|| note.to_string()),
+                ..attendee.clone()
+            },
+            false => attendee.clone(),
+        })
+        .collect();
+
+    Some(json!({ "attendees": attendees }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attendee(email: &str, status: Option<&str>, is_self: bool) -> Attendee {
+        Attendee {
+            email: email.to_string(),
+            response_status: status.map(str::to_string),
+            is_self,
+            ..Attendee::default()
+        }
+    }
+
+    #[test]
+    fn replies_as_the_current_user_only() {
+        let attendees = [
+            attendee("boss@example.com", Some("accepted"), false),
+            attendee("me@example.com", Some(NEEDS_ACTION), true),
+        ];
+
+        assert_eq!(reply(&attendees, Response::Tentative, "Running late"), Some(json!({ "attendees": [
+            { "email": "boss@example.com", "responseStatus": "accepted" },
+            { "email": "me@example.com", "responseStatus": "tentative", "comment": "Running late", "self": true },
+        ] })));
+        assert_eq!(reply(&attendees[..1], Response::Accepted, ""), None);
+    }
+
+    #[test]
+    fn detects_pending_invitations() {
+        assert!(needs_response(&attendee("me@example.com", Some(NEEDS_ACTION), true)));
+        assert!(needs_response(&attendee("me@example.com", None, true)));
+        assert!(!needs_response(&attendee("me@example.com", Some("declined"), true)));
+    }
+}
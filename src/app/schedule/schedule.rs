@@ -1,294 +1,1849 @@
-use std::process::Command;
+use std::collections::HashMap;
 
-use reqwest::blocking::Client;
-use chrono::{Utc, FixedOffset, TimeZone};
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
 use ratatui::{
     crossterm::event::{KeyCode, KeyEvent},
-    layout::Rect, 
+    layout::Rect,
+    style::Color,
     Frame,
 };
-use serde_json::json;
+use serde_json::{json, Map, Value};
 
-use crate::app::schedule::form::{Form, Mode as FormMode};
+use crate::app::config::{self, Browser, Config};
+use crate::app::date_parser::{self, When};
+use crate::app::schedule::{
+    calendars::CalendarPicker,
+    detail::Detail,
+    finder::{self, Availability, Finder, Search},
+    form::{Form, Mode as FormMode},
+    jump::Jump,
+    recurrence::{self, RepeatEnd, Scope},
+    reminder,
+    rsvp::{self, Rsvp},
+    scope::{Pending, ScopePrompt},
+};
+use crate::app::keymap::{Action, Context};
+use crate::app::tab::TabApp;
 use crate::app::ui::{
-    help::Help,
-    select_list::SelectList,
-    pane::Pane,
+    checkbox::CheckBox,
+    date_picker::DatePicker,
+    help::{HelpItem, HelpSection},
+    month_view::{self, MonthView},
+    select_list::{ColoredItem, SelectList},
+    pane::{Pane, Preview},
+    week_view::{self, DayEvent, WeekView},
+};
+use crate::app::interval::Interval;
+use crate::app::notification::Notifier;
+use crate::app::worker::Worker;
+use crate::app::google::{
+    api::ApiError,
+    account::{Account, Accounts},
+    calendar::{
+        self, Attendee, Calendar, CalendarApi, CalendarListEntry, ConferenceData, ConferenceSolutionKey,
+        CreateConferenceRequest, Event, EventTime, ReminderOverride, Reminders, EVENT_COLORS,
+    },
 };
-use crate::app::google::authentication::TokenInfo;
 
-const BASE_API: &str = "https://www.googleapis.com/calendar/v3/calendars/primary/events";
-const BROWSER_PATH: &str = "/mnt/c/Program Files/BraveSoftware/Brave-Browser/Application/brave.exe";
 const APP_TITLE: &str = "Schedule";
-const HELP_TITLE: &str = "Help Schedule";
+const DATE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const SECONDARY_FORMAT: &str = "%H:%M";
+const HEADER_FORMAT: &str = "%a %Y-%m-%d";
+const MONTH_FORMAT: &str = "%B %Y";
+const REPEAT_MARKER: &str = "↻";
+const INVITE_MARKER: &str = "✉";
+const PENDING_TITLE: &str = "Needs response";
+const ALL_DAY: &str = "All day";
+const PREVIEW_FORMAT: &str = "%a %Y-%m-%d %H:%M %Z";
+const INVALID_PREVIEW: &str = "Unrecognized date";
 
-#[derive(Debug)]
+#[derive(Debug, thiserror::Error)]
+pub enum ScheduleError {
+    #[error("Failed to load schedules: {0}")]
+    Read(ApiError),
+    #[error("Failed to load schedules from {calendar}: {source}")]
+    ReadCalendar {
+        calendar: String,
+        source: ApiError,
+    },
+    #[error("Failed to load calendar time zone: {0}")]
+    TimeZone(ApiError),
+    #[error("Failed to add schedule: {0}")]
+    Add(ApiError),
+    #[error("Failed to edit schedule: {0}")]
+    Edit(ApiError),
+    #[error("Failed to delete schedule: {0}")]
+    Delete(ApiError),
+    #[error("Failed to open schedule: {0}")]
+    Open(std::io::Error),
+    #[error("Schedule has no link to open")]
+    NoLink,
+    #[error("Failed to reply to invitation: {0}")]
+    Respond(ApiError),
+    #[error("Schedule is not an invitation")]
+    NotInvited,
+    #[error("Failed to load free/busy: {0}")]
+    FreeBusy(ApiError),
+    #[error("Invalid email \"{0}\"")]
+    InvalidEmail(String),
+    #[error("Enter at least one attendee email")]
+    NoAttendees,
+    #[error("Invalid duration \"{0}\", expected a duration like 30m or 1h30m")]
+    InvalidDuration(String),
+    #[error("Invalid reminders \"{0}\", expected up to 5 entries like popup 10m or email 1d")]
+    InvalidReminders(String),
+    #[error("Invalid date \"{0}\", expected yyyy-mm-dd")]
+    InvalidDate(String),
+    #[error("Invalid repeat end \"{0}\", expected yyyy-mm-dd or a count")]
+    InvalidRepeatEnd(String),
+    #[error("Invalid {field} \"{value}\", expected a date like tomorrow 3pm, fri 10:00 or yyyy-mm-dd hh:mm")]
+    InvalidTime {
+        field: &'static str,
+        value: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum View {
+    List,
+    Week,
+    Month,
+}
+
+type Loaded = (Vec<CalendarSource>, Vec<Schedule>, Vec<ScheduleError>);
+type Reloaded = ([DateTime<Utc>; 2], Loaded);
+
+#[derive(Debug, Clone)]
+pub struct CalendarSource {
+    pub account: String,
+    pub calendar: CalendarListEntry,
+}
+
+impl CalendarSource {
+    fn key(&self) -> (String, String) {
+        (self.account.clone(), self.calendar.id.clone())
+    }
+
+    fn color(&self) -> Option<Color> {
+        self.calendar.background_color.as_deref().and_then(|color| color.parse().ok())
+    }
+
+    fn is_shown(&self, visibility: &HashMap<(String, String), bool>) -> bool {
+        visibility
+            .get(&self.key())
+            .copied()
+            .unwrap_or(self.calendar.selected || self.calendar.primary)
+    }
+
+    fn label(&self, badge: bool) -> String {
+        if badge {
+            format!("[{}] {}", self.account, self.calendar.name())
+        } else {
+            self.calendar.name().to_string()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Schedule {
-    pub id: String,
-    pub summary: String,
-    pub start: String, 
-    pub end: String,
-    pub link: String,
-    pub description: String,
+    pub event: Event,
+    pub account: String,
+    pub calendar_id: String,
+    pub color: Option<Color>,
 }
 
 impl Schedule {
-    fn value(&self) -> String {
-        format!("{}\n  {} - {}", self.summary, self.start, self.end)
+    fn is_recurring(&self) -> bool {
+        self.event.recurring_event_id.is_some()
+    }
+
+    fn self_attendee(&self) -> Option<&Attendee> {
+        self.event.attendees.iter().find(|attendee| attendee.is_self)
+    }
+
+    fn needs_response(&self) -> bool {
+        self.self_attendee().is_some_and(rsvp::needs_response)
+    }
+
+    fn summary(&self) -> String {
+        let mut summary = self.event.summary.clone().unwrap_or_else(|| "(No title)".to_string());
+
+        if self.is_recurring() {
+            summary = format!("{} {}", REPEAT_MARKER, summary);
+        }
+        if self.needs_response() {
+            summary = format!("{} {}", INVITE_MARKER, summary);
+        }
+
+        summary
+    }
+
+    fn is_all_day(&self) -> bool {
+        is_all_day(&self.event)
+    }
+
+    fn value(&self, time_zone: Tz, secondary_time_zone: Option<Tz>, badge: bool) -> String {
+        let summary = self.summary();
+        let [start, end] = time_texts(&self.event, time_zone);
+        let mut time = match self.is_all_day() {
+            true if start == end => ALL_DAY.to_string(),
+            true => format!("{}, {} - {}", ALL_DAY, start, end),
+            false => format!("{} - {}", start, end),
+        };
+        if let Some(secondary) = secondary_time_zone.filter(|_| !self.is_all_day()) {
+            time = format!("{:<33}| {}", time, self.secondary_time(secondary));
+        }
+
+        if badge {
+            format!("[{}] {}\n  {}", self.account, summary, time)
+        } else {
+            format!("{}\n  {}", summary, time)
+        }
+    }
+
+    fn secondary_time(&self, time_zone: Tz) -> String {
+        let start = self.event.start.date_time.map(|start| start.with_timezone(&time_zone));
+        let end = self.event.end.date_time.map(|end| end.with_timezone(&time_zone));
+
+        match (start, end) {
+            (Some(start), Some(end)) => format!("{} - {} {}",
+                start.format(SECONDARY_FORMAT),
+                end.format(SECONDARY_FORMAT),
+                end.format("%Z"),
+            ),
+            _ => time_zone.name().to_string(),
+        }
+    }
+}
+
+fn is_all_day(event: &Event) -> bool {
+    event.start.date_time.is_none() && event.start.date.is_some()
+}
+
+fn last_date(event: &Event) -> Option<NaiveDate> {
+    let start = event.start.date?;
+
+    Some(event.end.date
+        .map(|end| end - Duration::days(1))
+        .filter(|last| *last > start)
+        .unwrap_or(start))
+}
+
+fn time_texts(event: &Event, time_zone: Tz) -> [String; 2] {
+    match (event.start.date, last_date(event)) {
+        (Some(start), Some(last)) if is_all_day(event) => [
+            start.format(DATE_FORMAT).to_string(),
+            last.format(DATE_FORMAT).to_string(),
+        ],
+        _ => [format_time(&event.start, time_zone), format_time(&event.end, time_zone)],
+    }
+}
+
+fn time_value(time: &EventTime) -> Value {
+    json!({
+        "date": time.date,
+        "dateTime": time.date_time,
+        "timeZone": time.time_zone,
+    })
+}
+
+fn is_email(text: &str) -> bool {
+    match text.split_once('@') {
+        Some((name, domain)) => {
+            !name.is_empty()
+                && !domain.contains('@')
+                && domain.split('.').count() > 1
+                && domain.split('.').all(|part| !part.is_empty())
+                && !text.contains(char::is_whitespace)
+        },
+        None => false,
     }
 }
 
+fn event_color(event: &Event) -> Option<Color> {
+    let index = event.color_id.as_deref()?.parse::<usize>().ok()?;
+
+    EVENT_COLORS.get(index.checked_sub(1)?)?.1.parse().ok()
+}
+
+fn has_meet(event: &Event) -> bool {
+    event.hangout_link.is_some()
+        || event.conference_data.as_ref().is_some_and(|conference| {
+            conference.video_uri().is_some() || conference.create_request.is_some()
+        })
+}
+
+fn meet_request() -> ConferenceData {
+    ConferenceData {
+        create_request: Some(CreateConferenceRequest {
+            request_id: Utc::now().timestamp_millis().to_string(),
+            conference_solution_key: ConferenceSolutionKey { key_type: calendar::MEET.to_string() },
+        }),
+        ..ConferenceData::default()
+    }
+}
+
+fn reminder_overrides(event: &Event) -> Vec<ReminderOverride> {
+    event.reminders
+        .as_ref()
+        .filter(|reminders| !reminders.use_default)
+        .map(|reminders| reminders.overrides.clone())
+        .unwrap_or_default()
+}
+
+fn format_time(time: &EventTime, time_zone: Tz) -> String {
+    match (time.date_time, time.date) {
+        (Some(date_time), _) => date_time.with_timezone(&time_zone).format(DATE_TIME_FORMAT).to_string(),
+        (None, Some(date)) => date.format(DATE_FORMAT).to_string(),
+        (None, None) => String::new(),
+    }
+}
+
+fn today(time_zone: Tz) -> NaiveDate {
+    Utc::now().with_timezone(&time_zone).date_naive()
+}
+
+fn local_time(time_zone: Tz, naive: NaiveDateTime) -> DateTime<Tz> {
+    time_zone
+        .from_local_datetime(&naive)
+        .earliest()
+        .unwrap_or_else(|| time_zone.from_utc_datetime(&naive))
+}
+
 #[derive(Debug)]
 pub struct Schedules<'a> {
     pub pane: Pane,
     pub schedules: Vec<Schedule>,
     pub list: SelectList<'a>,
     pub form: Form,
-    pub help: Help,
+    pub jump: Jump,
+    pub picker: CalendarPicker,
+    pub scope: ScopePrompt,
+    pub detail: Detail,
+    pub rsvp: Rsvp,
+    pub finder: Finder,
+    pub date_picker: DatePicker,
+    pub calendars: Vec<CalendarSource>,
+    loaded: Vec<Schedule>,
+    accounts: Accounts,
+    visibility: HashMap<(String, String), bool>,
+    form_calendars: Vec<(String, String)>,
+    target: Option<Schedule>,
+    browser: Browser,
+    time_zone: Tz,
+    secondary_time_zone: Option<Tz>,
+    detect_time_zone: bool,
+    start_date: NaiveDate,
+    days: u32,
+    view: View,
+    pending_only: bool,
+    selected_event: usize,
+    stale: bool,
+    worker: Worker<Result<Reloaded, ScheduleError>>,
+    zone_worker: Worker<Result<Calendar, ScheduleError>>,
+    busy_worker: Worker<Result<(Search, Availability), ScheduleError>>,
+    interval: Interval,
+    notifier: Notifier,
 }
 
 impl<'a> Schedules<'a> {
-    pub fn new(token_info: &TokenInfo) -> Self {
-        Self {
+    pub fn new(config: &Config, accounts: &Accounts, notifier: &Notifier) -> Self {
+        let time_zone = config.time_zone.unwrap_or_else(config::system_time_zone);
+
+        let mut schedules = Self {
             pane: Pane::new(APP_TITLE),
-            schedules: Self::read(token_info),
+            schedules: Vec::new(),
             list: SelectList::new(),
-            form: Form::new() ,
-            help: Help::new(HELP_TITLE),
-        }
+            form: Form::new(),
+            jump: Jump::new(),
+            picker: CalendarPicker::new(),
+            scope: ScopePrompt::new(),
+            detail: Detail::new(),
+            rsvp: Rsvp::new(),
+            finder: Finder::new(),
+            date_picker: DatePicker::new(),
+            calendars: Vec::new(),
+            loaded: Vec::new(),
+            accounts: accounts.clone(),
+            visibility: HashMap::new(),
+            form_calendars: Vec::new(),
+            target: None,
+            browser: config.browser.clone(),
+            time_zone,
+            secondary_time_zone: config.secondary_time_zone,
+            detect_time_zone: config.time_zone.is_none(),
+            start_date: today(time_zone),
+            days: config.schedule.days,
+            view: View::List,
+            pending_only: false,
+            selected_event: 0,
+            stale: false,
+            worker: Worker::new(),
+            zone_worker: Worker::new(),
+            busy_worker: Worker::new(),
+            interval: Interval::from_secs(config.refresh.schedule),
+            notifier: notifier.clone(),
+        };
+        schedules.load_time_zone();
+        schedules.set_title();
+
+        schedules
     }
 
-    pub fn read(token_info: &TokenInfo) -> Vec<Schedule> {
-        let client = Client::new();
-        let [time_min, time_max] = Self::time_min_max();
+    fn load_time_zone(&mut self) {
+        if !self.detect_time_zone {
+            return;
+        }
 
-        let response = client.get(BASE_API)
-            .bearer_auth(&token_info.access_token)
-            .query(&[
-                ("orderBy", "startTime"),
-                ("singleEvents", "true"),
-                ("timeMin", &time_min),
-                ("timeMax", &time_max),
-            ])
-            .send()
-            .unwrap();
-
-        let mut schedules = Vec::<Schedule>::new();
-        if response.status().is_success() {
-            let events: serde_json::Value = response.json().unwrap();
-            if let Some(items) = events["items"].as_array() {
-                if !items.is_empty() {
-                    for item in items {
-                        let id = item["id"].to_string().replace("\"", "");
-                        let summary = item["summary"].as_str().unwrap_or("No summary");
-                        let description = item["description"].as_str().unwrap_or("No discription");
-                        let start = item["start"]["dateTime"]
-                            .as_str()
-                            .or_else(|| item["start"]["date"].as_str())
-                            .unwrap_or("No start time");
-                        let end = item["end"]["dateTime"]
-                            .as_str()
-                            .or_else(|| item["end"]["date"].as_str())
-                            .unwrap_or("No end time");
-                        let link = item["htmlLink"].to_string().replace("\"", "");
-
-                        let schedule = Schedule {
-                            id: id.to_string(),
-                            summary: summary.to_string(),
-                            start: start.to_string(),
-                            end: end.to_string(),
-                            link,
-                            description: description.to_string(),
-                        };
-
-                        schedules.push(schedule);
-                    }
-                }
+        let calendar = self.accounts.current().calendar.clone();
+
+        self.zone_worker.spawn(move || calendar.calendar().map_err(ScheduleError::TimeZone));
+    }
+
+    fn set_time_zone(&mut self, calendar: Calendar) {
+        if let Some(time_zone) = calendar.time_zone.and_then(|name| name.parse::<Tz>().ok()) {
+            if self.start_date == today(self.time_zone) {
+                self.start_date = today(time_zone);
             }
+            self.time_zone = time_zone;
+            self.set_title();
+        }
+    }
+
+    fn set_title(&mut self) {
+        let [start_date, end_date] = self.visible_dates();
+        let last_date = end_date - Duration::days(1);
+
+        let mut title = match self.view {
+            View::Month => format!("{} {}", APP_TITLE, self.start_date.format(MONTH_FORMAT)),
+            _ if start_date == last_date => format!("{} {}", APP_TITLE, start_date.format(HEADER_FORMAT)),
+            _ => format!("{} {} - {}", APP_TITLE, start_date.format(HEADER_FORMAT), last_date.format(HEADER_FORMAT)),
         };
+        if self.pending_only {
+            title = format!("{} ({})", title, PENDING_TITLE);
+        }
 
-        schedules
+        self.pane.set_title(&title);
     }
 
-    pub fn time_min_max() -> [String; 2] {
-        let jst = FixedOffset::east_opt(9 * 3600).expect("Invalid offset");
-        let now = Utc::now().with_timezone(&jst);
-        
-        let time_min_naive = now
-            .date_naive()
-            .and_hms_opt(0, 0, 0)
-            .expect("Failed to create time_min");
-        let time_min_result = jst
-            .from_local_datetime(&time_min_naive);
-        let time_min = match time_min_result {
-            chrono::LocalResult::Single(dt) => dt.to_rfc3339(),
-            _ => return ["".to_string(), "".to_string()],
+    fn visible_dates(&self) -> [NaiveDate; 2] {
+        match self.view {
+            View::List => [self.start_date, self.start_date + Duration::days(i64::from(self.days))],
+            View::Week => {
+                let start_date = week_view::week_start(self.start_date);
+                [start_date, start_date + Duration::days(7)]
+            },
+            View::Month => [month_view::month_start(self.start_date), month_view::month_end(self.start_date)],
+        }
+    }
+
+    fn go_to(&mut self, date: NaiveDate) {
+        let visible = self.visible_dates();
+
+        self.start_date = date;
+        self.selected_event = 0;
+        self.list.top();
+        self.set_title();
+
+        if self.visible_dates() != visible {
+            self.refresh();
+        }
+    }
+
+    fn toggle_view(&mut self) {
+        let visible = self.visible_dates();
+
+        self.view = match self.view {
+            View::List => View::Week,
+            View::Week => View::Month,
+            View::Month => View::List,
         };
+        self.selected_event = 0;
+        self.list.top();
+        self.set_title();
 
-        let time_max_naive = (now + chrono::Duration::days(1))
-            .date_naive()
-            .and_hms_opt(23, 59, 59)
+        if self.visible_dates() != visible {
+            self.refresh();
+        }
+    }
+
+    fn start_date_of(&self, schedule: &Schedule) -> Option<NaiveDate> {
+        schedule.event.start
+            .in_zone(&self.time_zone)
+            .map(|start| start.date_naive())
+    }
+
+    fn day_indices(&self, date: NaiveDate) -> Vec<usize> {
+        self.schedules
+            .iter()
+            .enumerate()
+            .filter(|(_, schedule)| self.start_date_of(schedule) == Some(date))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn selected(&self) -> Option<usize> {
+        match self.view {
+            View::List => (self.list.index < self.schedules.len()).then_some(self.list.index),
+            _ => self.day_indices(self.start_date).get(self.selected_event).copied(),
+        }
+    }
+
+    fn move_selection(&mut self, rows: i64) {
+        match self.view {
+            View::List if rows < 0 => self.list.up(),
+            View::List => self.list.down(),
+            View::Week => {
+                let count = self.day_indices(self.start_date).len();
+                self.selected_event = self.selected_event
+                    .saturating_add_signed(rows as isize)
+                    .min(count.saturating_sub(1));
+            },
+            View::Month => self.shift(rows * 7),
+        }
+    }
+
+    fn move_day(&mut self, days: i64) {
+        match self.view {
+            View::List => self.move_selection(days),
+            _ => self.shift(days),
+        }
+    }
+
+    fn day_events(&self) -> Vec<DayEvent> {
+        let badge = self.accounts.is_merged();
+        let selected = self.selected();
+
+        self.schedules
+            .iter()
+            .enumerate()
+            .filter_map(|(i, schedule)| {
+                let start = schedule.event.start.in_zone(&self.time_zone)?;
+                let end = schedule.event.end.in_zone(&self.time_zone);
+                let summary = schedule.summary();
+
+                Some(DayEvent {
+                    date: start.date_naive(),
+                    last_date: last_date(&schedule.event).unwrap_or(start.date_naive()),
+                    start: schedule.event.start.date_time.map(|_| start.time()),
+                    end: end
+                        .filter(|end| end.date_naive() == start.date_naive())
+                        .map(|end| end.time()),
+                    title: if badge { format!("[{}] {}", schedule.account, summary) } else { summary },
+                    color: schedule.color,
+                    selected: selected == Some(i),
+                })
+            })
+            .collect()
+    }
+
+    fn filter_schedules(&mut self) {
+        self.schedules = self.loaded
+            .iter()
+            .filter(|schedule| !self.pending_only || schedule.needs_response())
+            .cloned()
+            .collect();
+        self.sort_schedules();
+        self.list.clamp(self.schedules.len());
+    }
+
+    fn toggle_pending(&mut self) {
+        self.pending_only = !self.pending_only;
+        self.filter_schedules();
+        self.set_title();
+    }
+
+    fn sort_schedules(&mut self) {
+        let time_zone = self.time_zone;
+
+        self.schedules.sort_by_key(|schedule| {
+            let start = schedule.event.start.in_zone(&time_zone);
+            (start.as_ref().map(|start| start.date_naive()), !schedule.is_all_day(), start)
+        });
+    }
+
+    fn shift(&mut self, days: i64) {
+        self.go_to(self.start_date + Duration::days(days));
+    }
+
+    fn jump_to(&mut self) {
+        let text = self.jump.date.text.trim().to_string();
+
+        match NaiveDate::parse_from_str(&text, DATE_FORMAT) {
+            Ok(date) => {
+                self.jump.popup.active = false;
+                self.go_to(date);
+            },
+            Err(_) => self.notifier.error(ScheduleError::InvalidDate(text).to_string()),
+        }
+    }
+
+    fn range(&self) -> [DateTime<Utc>; 2] {
+        let [start_date, end_date] = self.visible_dates();
+
+        Self::time_min_max(self.time_zone, start_date, end_date)
+    }
+
+    fn groups(&self) -> Vec<(String, Vec<ColoredItem>)> {
+        let badge = self.accounts.is_merged();
+        let mut groups: Vec<(NaiveDate, Vec<ColoredItem>)> = Vec::new();
+
+        for schedule in &self.schedules {
+            let date = schedule.event.start
+                .in_zone(&self.time_zone)
+                .map(|start| start.date_naive())
+                .unwrap_or(self.start_date)
+                .max(self.start_date);
+            let value = (schedule.color, schedule.value(self.time_zone, self.secondary_time_zone, badge));
+
+            match groups.last_mut() {
+                Some((last, values)) if *last == date => values.push(value),
+                _ => groups.push((date, vec![value])),
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|(date, values)| (date.format(HEADER_FORMAT).to_string(), values))
+            .collect()
+    }
+
+    pub fn read_all(
+        accounts: &[Account],
+        range: [DateTime<Utc>; 2],
+        visibility: &HashMap<(String, String), bool>,
+    ) -> Result<Loaded, ApiError> {
+        let mut calendars = Vec::new();
+        let mut schedules = Vec::new();
+        let mut errors = Vec::new();
+        for account in accounts {
+            let (account_calendars, account_schedules, account_errors) = Self::read(account, range, visibility)?;
+            calendars.extend(account_calendars);
+            schedules.extend(account_schedules);
+            errors.extend(account_errors);
+        }
+
+        Ok((calendars, schedules, errors))
+    }
+
+    pub fn read(
+        account: &Account,
+        range: [DateTime<Utc>; 2],
+        visibility: &HashMap<(String, String), bool>,
+    ) -> Result<Loaded, ApiError> {
+        let [time_min, time_max] = range;
+
+        let calendars: Vec<CalendarSource> = account.calendar
+            .list_calendars()?
+            .into_iter()
+            .map(|calendar| CalendarSource { account: account.name.clone(), calendar })
+            .collect();
+
+        let mut schedules = Vec::new();
+        let mut errors = Vec::new();
+        for source in calendars.iter().filter(|source| source.is_shown(visibility)) {
+            let events = match account.calendar.list_events(&source.calendar.id, time_min, time_max) {
+                Ok(events) => events,
+                Err(err) => {
+                    errors.push(ScheduleError::ReadCalendar { calendar: source.label(true), source: err });
+                    continue;
+                },
+            };
+
+            schedules.extend(events.into_iter().map(|event| Schedule {
+                color: event_color(&event).or_else(|| source.color()),
+                event,
+                account: account.name.clone(),
+                calendar_id: source.calendar.id.clone(),
+            }));
+        }
+
+        Ok((calendars, schedules, errors))
+    }
+
+    fn reload(&self) -> impl FnOnce() -> Result<Reloaded, ScheduleError> + Send + 'static {
+        let accounts = self.accounts.selected();
+        let range = self.range();
+        let visibility = self.visibility.clone();
+
+        move || {
+            let loaded = Schedules::read_all(&accounts, range, &visibility).map_err(ScheduleError::Read)?;
+            Ok((range, loaded))
+        }
+    }
+
+    fn open_calendars(&mut self) {
+        let badge = self.accounts.is_merged();
+        let checkboxes = self.calendars
+            .iter()
+            .map(|source| {
+                let mut checkbox = CheckBox::new(&source.label(badge)).with_color(source.color());
+                if source.is_shown(&self.visibility) {
+                    checkbox.checked();
+                }
+                checkbox
+            })
+            .collect();
+
+        self.picker.open(checkboxes);
+    }
+
+    fn apply_calendars(&mut self) {
+        for (source, checked) in self.calendars.iter().zip(self.picker.checked()) {
+            self.visibility.insert(source.key(), checked);
+        }
+        self.picker.popup.active = false;
+
+        self.refresh();
+    }
+
+    fn set_form_calendars(&mut self) {
+        let badge = self.accounts.is_merged();
+        let current = self.accounts.current().name.clone();
+        let writable: Vec<&CalendarSource> = self.calendars
+            .iter()
+            .filter(|source| source.calendar.is_writable())
+            .collect();
+
+        self.form.calendars = writable.iter().map(|source| source.label(badge)).collect();
+        self.form_calendars = writable.iter().map(|source| source.key()).collect();
+        self.form.calendar.index = writable
+            .iter()
+            .position(|source| source.account == current && source.calendar.primary)
+            .unwrap_or_default();
+
+        if self.form_calendars.is_empty() {
+            self.form.calendars = vec![calendar::PRIMARY.to_string()];
+            self.form_calendars = vec![(current, calendar::PRIMARY.to_string())];
+        }
+    }
+
+    pub fn time_min_max(time_zone: Tz, start_date: NaiveDate, end_date: NaiveDate) -> [DateTime<Utc>; 2] {
+        let time_min = start_date
+            .and_hms_opt(0, 0, 0)
+            .expect("Failed to create time_min");
+        let time_max = end_date
+            .and_hms_opt(0, 0, 0)
             .expect("Failed to create time_max");
-        let time_max_result = jst
-            .from_local_datetime(&time_max_naive);
-        let time_max = match time_max_result {
-            chrono::LocalResult::Single(dt) => dt.to_rfc3339(),
-            _ => return ["".to_string(), "".to_string()],
+
+        [
+            local_time(time_zone, time_min).with_timezone(&Utc),
+            local_time(time_zone, time_max).with_timezone(&Utc),
+        ]
+    }
+
+    fn now(&self) -> DateTime<Tz> {
+        Utc::now().with_timezone(&self.time_zone)
+    }
+
+    fn event_time(&self, naive: NaiveDateTime) -> EventTime {
+        EventTime::at(&local_time(self.time_zone, naive), self.time_zone.name())
+    }
+
+    fn form_start(&self) -> Result<When, ScheduleError> {
+        let text = &self.form.start.text;
+
+        date_parser::parse(text, &self.now())
+            .ok_or_else(|| ScheduleError::InvalidTime { field: "start", value: text.to_string() })
+    }
+
+    fn form_end(&self, start: When) -> Result<When, ScheduleError> {
+        let text = &self.form.end.text;
+        let all_day = self.form.is_all_day();
+
+        if all_day && text.trim().is_empty() {
+            return Ok(When::Date(start.date()));
+        }
+        if let Some(duration) = date_parser::parse_duration(text) {
+            let invalid = || ScheduleError::InvalidTime { field: "end", value: text.to_string() };
+            let end = start.date_time().checked_add_signed(duration).ok_or_else(invalid)?;
+            return Ok(match all_day {
+                true => When::Date(end.checked_sub_signed(Duration::seconds(1)).ok_or_else(invalid)?.date().max(start.date())),
+                false => When::DateTime(end),
+            });
+        }
+
+        date_parser::parse(text, &self.now())
+            .ok_or_else(|| ScheduleError::InvalidTime { field: "end", value: text.to_string() })
+    }
+
+    fn form_times(&self) -> Result<[EventTime; 2], ScheduleError> {
+        let start = self.form_start()?;
+        let end = self.form_end(start)?;
+
+        if self.form.is_all_day() {
+            Ok([
+                EventTime { date: Some(start.date()), ..EventTime::default() },
+                EventTime { date: Some(end.date() + Duration::days(1)), ..EventTime::default() },
+            ])
+        } else {
+            Ok([self.event_time(start.date_time()), self.event_time(end.date_time())])
+        }
+    }
+
+    fn preview(&self, text: &str, when: Result<When, ScheduleError>) -> Option<Preview> {
+        if text.trim().is_empty() {
+            return None;
+        }
+
+        Some(match when {
+            Ok(when) if self.form.is_all_day() => Preview::Valid(when.date().format(HEADER_FORMAT).to_string()),
+            Ok(when) => Preview::Valid(local_time(self.time_zone, when.date_time()).format(PREVIEW_FORMAT).to_string()),
+            Err(_) => Preview::Invalid(INVALID_PREVIEW.to_string()),
+        })
+    }
+
+    fn update_previews(&mut self) {
+        let start = self.form_start();
+        let end = self.form_end(*start.as_ref().unwrap_or(&When::DateTime(self.now().naive_local())));
+
+        let start = self.preview(&self.form.start.text, start);
+        let end = self.preview(&self.form.end.text, end);
+        self.form.start_pane.preview = start;
+        self.form.end_pane.preview = end;
+    }
+
+    fn open_date_picker(&mut self) {
+        let when = if self.form.start.active {
+            self.form_start()
+        } else if self.form.end.active {
+            self.form_start().and_then(|start| self.form_end(start))
+        } else {
+            return;
         };
 
-        [time_min, time_max]
+        let date_time = when.map_or_else(|_| self.now().naive_local(), |when| when.date_time());
+        let time = (!self.form.is_all_day()).then(|| date_time.time());
+        self.date_picker.open(date_time.date(), time);
     }
 
-    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let pane = self.pane.render(frame, area);
+    fn pick_date(&mut self) {
+        self.date_picker.popup.active = false;
 
-        let list = self.schedules.iter().map(|schedule| {
-            schedule.value().clone()
+        let date = self.date_picker.date;
+        let text = match self.date_picker.time {
+            Some(time) => date.and_time(time).format(DATE_TIME_FORMAT).to_string(),
+            None => date.format(DATE_FORMAT).to_string(),
+        };
+        if self.form.start.active {
+            self.form.start.set(&text);
+        } else if self.form.end.active {
+            self.form.end.set(&text);
+        }
+        self.update_previews();
+    }
+
+    fn form_event(&self) -> Result<Event, ScheduleError> {
+        let description = self.form.description.text.to_string();
+        let location = self.form.location.text.trim().to_string();
+        let visibility = self.form.visibility_value();
+        let [start, end] = self.form_times()?;
+        let reminders = self.form_reminders()?;
+
+        Ok(Event {
+            summary: Some(self.form.summary.text.to_string()),
+            description: (!description.is_empty()).then_some(description),
+            location: (!location.is_empty()).then_some(location),
+            color_id: self.form.color_id(),
+            visibility: (visibility != "default").then_some(visibility),
+            conference_data: self.form.wants_meet().then(meet_request),
+            reminders: (!reminders.is_empty()).then_some(Reminders { use_default: false, overrides: reminders }),
+            start,
+            end,
+            recurrence: self.form_recurrence()?,
+            attendees: self
+                .form_emails()?
+                .into_iter()
+                .map(|email| Attendee { email, ..Attendee::default() })
+                .collect(),
+            ..Event::default()
         })
-        .collect();
+    }
+
+    fn form_emails(&self) -> Result<Vec<String>, ScheduleError> {
+        let emails = self.form.attendee_emails();
 
-        self.list.render(frame, pane, list);
+        match emails.iter().find(|email| !is_email(email)) {
+            Some(email) => Err(ScheduleError::InvalidEmail(email.to_string())),
+            None => Ok(emails),
+        }
+    }
+
+    fn form_reminders(&self) -> Result<Vec<ReminderOverride>, ScheduleError> {
+        let text = &self.form.reminders.text;
+
+        reminder::parse(text).ok_or_else(|| ScheduleError::InvalidReminders(text.to_string()))
+    }
+
+    fn form_recurrence(&self) -> Result<Vec<String>, ScheduleError> {
+        if !matches!(self.form.mode, FormMode::New) {
+            return Ok(Vec::new());
+        }
+
+        let text = self.form.ends.text.trim().to_string();
+        let end = RepeatEnd::parse(&text).ok_or(ScheduleError::InvalidRepeatEnd(text))?;
+
+        let time_zone = (!self.form.is_all_day()).then_some(self.time_zone);
+
+        Ok(recurrence::rule(self.form.frequency(), &self.form.repeat_weekdays(), &end, time_zone)
+            .into_iter()
+            .collect())
+    }
+
+    fn form_patch(&self, event: &Event) -> Result<Map<String, Value>, ScheduleError> {
+        let mut patch = Map::new();
+
+        if self.form.summary.text != event.summary.as_deref().unwrap_or_default() {
+            patch.insert("summary".to_string(), json!(self.form.summary.text));
+        }
+        if self.form.description.text != event.description.as_deref().unwrap_or_default() {
+            patch.insert("description".to_string(), json!(self.form.description.text));
+        }
+
+        let [start, end] = time_texts(event, self.time_zone);
+        if self.form.is_all_day() != is_all_day(event) || self.form.start.text != start || self.form.end.text != end {
+            let [start, end] = self.form_times()?;
+            patch.insert("start".to_string(), time_value(&start));
+            patch.insert("end".to_string(), time_value(&end));
+        }
+
+        let location = self.form.location.text.trim();
+        if location != event.location.as_deref().unwrap_or_default() {
+            patch.insert("location".to_string(), json!(location));
+        }
+
+        let emails = self.form_emails()?;
+        if emails.iter().ne(event.attendees.iter().map(|attendee| &attendee.email)) {
+            let attendees: Vec<Attendee> = emails
+                .into_iter()
+                .map(|email| match event.attendees.iter().find(|attendee| attendee.email == email) {
+                    Some(attendee) => attendee.clone(),
+                    None => Attendee { email, ..Attendee::default() },
+                })
+                .collect();
+            patch.insert("attendees".to_string(), json!(attendees));
+        }
+
+        if self.form.color_id() != event.color_id {
+            patch.insert("colorId".to_string(), json!(self.form.color_id()));
+        }
+
+        let visibility = self.form.visibility_value();
+        if visibility != event.visibility.as_deref().unwrap_or("default") {
+            patch.insert("visibility".to_string(), json!(visibility));
+        }
+
+        let reminders = self.form_reminders()?;
+        if reminders != reminder_overrides(event) {
+            let value = match reminders.is_empty() {
+                true => json!({ "useDefault": true }),
+                false => json!(Reminders { use_default: false, overrides: reminders }),
+            };
+            patch.insert("reminders".to_string(), value);
+        }
+
+        if self.form.wants_meet() != has_meet(event) {
+            let value = match self.form.wants_meet() {
+                true => json!(meet_request()),
+                false => Value::Null,
+            };
+            patch.insert("conferenceData".to_string(), value);
+        }
+
+        Ok(patch)
+    }
+
+    fn calendar_label(&self, schedule: &Schedule) -> String {
+        self.calendars
+            .iter()
+            .find(|source| source.account == schedule.account && source.calendar.id == schedule.calendar_id)
+            .map(|source| source.label(self.accounts.is_merged()))
+            .unwrap_or_else(|| schedule.calendar_id.clone())
+    }
+
+    fn show_detail(&mut self) {
+        let Some(index) = self.selected() else {
+            return;
+        };
+
+        let schedule = &self.schedules[index];
+        let calendar = self.calendar_label(schedule);
+        self.detail.open(&schedule.event, &calendar, self.time_zone);
     }
 
     pub fn open(&self) {
-        let schedule: String = self.schedules[self.list.index].link.to_string();
+        let Some(index) = self.selected() else {
+            return;
+        };
+
+        let result = match &self.schedules[index].event.html_link {
+            Some(link) => self.browser.open(link).map_err(ScheduleError::Open),
+            None => Err(ScheduleError::NoLink),
+        };
 
-        Command::new(BROWSER_PATH)
-            .arg(schedule)
-            .output()
-            .expect("Failed to execute open_schedule");
+        if let Err(err) = result {
+            self.notifier.error(err.to_string());
+        }
     }
 
-    pub fn add(&mut self, token_info: &TokenInfo) {
-        let client = Client::new();
+    pub fn add(&mut self) {
+        let event = match self.form_event() {
+            Ok(event) => event,
+            Err(err) => return self.notifier.error(err.to_string()),
+        };
 
-        let event = json!({
-            "summary": self.form.summary.text.to_string(),
-            "description": self.form.description.text.to_string(),
-            "start": {
-                "dateTime": format!("{}+09:00", self.form.start.text.to_string()), 
-                "timeZone": "Asia/Tokyo".to_string(),
-            },
-            "end": {
-                "dateTime": format!("{}+09:00", self.form.end.text.to_string()), 
-                "timeZone": "Asia/Tokyo".to_string(),
-            },
-        });
+        let (account, calendar_id) = self.form_calendars
+            .get(self.form.calendar.index)
+            .cloned()
+            .unwrap_or_else(|| (self.accounts.current().name.clone(), calendar::PRIMARY.to_string()));
+        let calendar = self.accounts.get(&account).calendar.clone();
+        let reload = self.reload();
+        let notifier = self.notifier.clone();
 
-        let _ = client
-            .post(BASE_API)
-            .bearer_auth(&token_info.access_token)
-            .json(&event)
-            .send();
+        self.worker.spawn(move || {
+            calendar.insert_event(&calendar_id, &event).map_err(ScheduleError::Add)?;
+            notifier.info("Added schedule");
 
-        self.schedules = Self::read(token_info);
+            reload()
+        });
 
         self.form.popup.active = false;
         self.form.all_clear();
     }
 
-    pub fn edit(&mut self, token_info: &TokenInfo) {
-        let client = Client::new();
+    fn hold_target(&mut self) {
+        self.target = self.selected().map(|index| self.schedules[index].clone());
+    }
+
+    fn is_recurring(&self) -> bool {
+        self.target.as_ref().is_some_and(Schedule::is_recurring)
+    }
+
+    fn submit_edit(&mut self) {
+        let changed = self.target
+            .as_ref()
+            .and_then(|schedule| self.form_patch(&schedule.event).ok())
+            .is_some_and(|patch| !patch.is_empty());
+
+        if changed && self.is_recurring() {
+            self.form.popup.active = false;
+            self.scope.open(Pending::Edit);
+        } else {
+            self.edit(Scope::This);
+        }
+    }
+
+    fn submit_delete(&mut self) {
+        self.hold_target();
+        if self.is_recurring() {
+            self.scope.open(Pending::Delete);
+        } else {
+            self.delete(Scope::This);
+        }
+    }
 
-        let schedule = &self.schedules[self.list.index];
+    fn open_rsvp(&mut self) {
+        self.hold_target();
+        let attendee = self.target.as_ref().and_then(Schedule::self_attendee);
 
-        let event = json!({
-            "summary": self.form.summary.text.to_string(),
-            "description": self.form.description.text.to_string(),
-            "start": {
-                "dateTime": self.form.start.text.to_string(), 
-                "timeZone": "Asia/Tokyo".to_string(),
+        match attendee {
+            Some(attendee) => self.rsvp.open(attendee),
+            None => self.notifier.error(ScheduleError::NotInvited.to_string()),
+        }
+    }
+
+    pub fn respond(&mut self) {
+        let (response, note) = self.rsvp.close();
+        let Some(schedule) = &self.target else {
+            return;
+        };
+
+        let Some(patch) = rsvp::reply(&schedule.event.attendees, response, &note) else {
+            return self.notifier.error(ScheduleError::NotInvited.to_string());
+        };
+        let id = schedule.event.id.clone();
+        let calendar_id = schedule.calendar_id.clone();
+        let calendar = self.accounts.get(&schedule.account).calendar.clone();
+        let reload = self.reload();
+        let notifier = self.notifier.clone();
+
+        self.worker.spawn(move || {
+            calendar.patch_event(&calendar_id, &id, &patch).map_err(ScheduleError::Respond)?;
+            notifier.info("Replied to invitation");
+
+            reload()
+        });
+    }
+
+    fn finder_search(&self) -> Result<Search, ScheduleError> {
+        let emails: Vec<String> = self.finder.attendees.text
+            .split(',')
+            .map(str::trim)
+            .filter(|email| !email.is_empty())
+            .map(str::to_string)
+            .collect();
+        if emails.is_empty() {
+            return Err(ScheduleError::NoAttendees);
+        }
+        if let Some(email) = emails.iter().find(|email| !is_email(email)) {
+            return Err(ScheduleError::InvalidEmail(email.to_string()));
+        }
+
+        let text = &self.finder.duration.text;
+        let duration = date_parser::parse_duration(text)
+            .filter(|duration| *duration > Duration::zero())
+            .ok_or_else(|| ScheduleError::InvalidDuration(text.to_string()))?;
+
+        let date = |field: &'static str, text: &str| {
+            date_parser::parse(text, &self.now())
+                .map(|when| when.date())
+                .ok_or_else(|| ScheduleError::InvalidTime { field, value: text.to_string() })
+        };
+        let from = date("from", &self.finder.from.text)?;
+        let to = date("to", &self.finder.to.text)?.max(from);
+
+        Ok(Search { emails, duration, from, to })
+    }
+
+    fn find_time(&mut self) {
+        let search = match self.finder_search() {
+            Ok(search) => search,
+            Err(err) => return self.notifier.error(err.to_string()),
+        };
+
+        let calendar = self.accounts.current().calendar.clone();
+        let [time_min, time_max] = Self::time_min_max(self.time_zone, search.from, search.to + Duration::days(1));
+        let time_zone = self.time_zone;
+
+        self.busy_worker.spawn(move || {
+            let free_busy = calendar.free_busy(&search.ids(), time_min, time_max).map_err(ScheduleError::FreeBusy)?;
+            let availability = finder::availability(&search, &free_busy, time_zone, Utc::now());
+
+            Ok((search, availability))
+        });
+    }
+
+    fn pick_slot(&mut self) {
+        let (Some((start, end)), Some(search)) = (self.finder.selected_slot(), self.finder.search.clone()) else {
+            return;
+        };
+
+        self.finder.popup.active = false;
+        self.open_add();
+        self.form.start.set(&start.with_timezone(&self.time_zone).format(DATE_TIME_FORMAT).to_string());
+        self.form.end.set(&end.with_timezone(&self.time_zone).format(DATE_TIME_FORMAT).to_string());
+        self.form.attendees.set(&search.emails.join(", "));
+        self.update_previews();
+    }
+
+    fn open_add(&mut self) {
+        self.form.popup.title = "Add Schedule".to_string();
+        self.form.mode = FormMode::New;
+        self.form.popup.active = true;
+        self.form.all_clear();
+        self.form.set_time_zone(self.time_zone.name());
+        self.set_form_calendars();
+        self.update_previews();
+        self.form.active_summary();
+    }
+
+    fn apply_scope(&mut self) {
+        match self.scope.close() {
+            Some((Pending::Edit, scope)) => self.edit(scope),
+            Some((Pending::Delete, scope)) => self.delete(scope),
+            None => {},
+        }
+    }
+
+    pub fn edit(&mut self, scope: Scope) {
+        let Some(schedule) = &self.target else {
+            return;
+        };
+
+        let patch = match self.form_patch(&schedule.event) {
+            Ok(patch) if patch.is_empty() => {
+                self.form.popup.active = false;
+                self.form.all_clear();
+                return self.notifier.info("No changes to save");
             },
-            "end": {
-                "dateTime": self.form.end.text.to_string(), 
-                "timeZone": "Asia/Tokyo".to_string(),
+            Ok(patch) => patch,
+            Err(err) => return self.notifier.error(err.to_string()),
+        };
+        let following = match scope {
+            Scope::Following => match self.form_event() {
+                Ok(event) => Some(event),
+                Err(err) => return self.notifier.error(err.to_string()),
             },
-        });
+            _ => None,
+        };
 
-        let url = format!("{}/{}", BASE_API, schedule.id);
+        let instance = schedule.event.clone();
+        let calendar_id = schedule.calendar_id.clone();
+        let calendar = self.accounts.get(&schedule.account).calendar.clone();
+        let reload = self.reload();
+        let notifier = self.notifier.clone();
 
-        let _ = client
-            .put(url)
-            .bearer_auth(&token_info.access_token)
-            .json(&event)
-            .send();
+        self.worker.spawn(move || {
+            edit_series(calendar.as_ref(), &calendar_id, &instance, scope, patch, following)
+                .map_err(ScheduleError::Edit)?;
+            notifier.info("Edited schedule");
 
-        self.schedules = Self::read(token_info);
+            reload()
+        });
 
         self.form.popup.active = false;
         self.form.all_clear();
     }
 
-    pub fn delete(&mut self, token_info: &TokenInfo) {
-        let client = Client::new();
+    pub fn delete(&mut self, scope: Scope) {
+        let Some(schedule) = &self.target else {
+            return;
+        };
 
-        let schedule = &self.schedules[self.list.index];
+        let instance = schedule.event.clone();
+        let calendar_id = schedule.calendar_id.clone();
+        let calendar = self.accounts.get(&schedule.account).calendar.clone();
+        let reload = self.reload();
+        let notifier = self.notifier.clone();
 
-        let url = format!("{}/{}", BASE_API, schedule.id);
+        self.worker.spawn(move || {
+            delete_series(calendar.as_ref(), &calendar_id, &instance, scope).map_err(ScheduleError::Delete)?;
+            notifier.info("Deleted schedule");
 
-        let _ = client
-            .delete(url)
-            .bearer_auth(&token_info.access_token)
-            .send();
+            reload()
+        });
+    }
+}
+
+fn following_start(instance: &Event, series: &Event) -> Option<EventTime> {
+    let start = instance.original_start_time.as_ref().unwrap_or(&instance.start);
 
-        self.schedules = Self::read(token_info);
+    (start.in_zone(&Utc)? > series.start.in_zone(&Utc)?).then(|| start.clone())
+}
+
+fn shift_times(patch: &mut Map<String, Value>, instance: &Event, series: &Event) {
+    for (field, instance_time, series_time) in [
+        ("start", &instance.start, &series.start),
+        ("end", &instance.end, &series.end),
+    ] {
+        let Some(time) = patch.get(field).and_then(|value| serde_json::from_value::<EventTime>(value.clone()).ok()) else {
+            continue;
+        };
+        let shifted = match (time.date, instance_time.date, series_time.date) {
+            (Some(date), Some(instance), Some(series)) => Some(EventTime {
+                date: Some(series + (date - instance)),
+                ..EventTime::default()
+            }),
+            (None, None, None) => time.in_zone(&Utc)
+                .zip(instance_time.in_zone(&Utc))
+                .zip(series_time.in_zone(&Utc))
+                .map(|((date_time, instance), series)| {
+                    EventTime::at(&(series + (date_time - instance)), time.time_zone.as_deref().unwrap_or("UTC"))
+                }),
+            _ => None,
+        };
+
+        if let Some(shifted) = shifted {
+            patch.insert(field.to_string(), time_value(&shifted));
+        }
     }
+}
 
-    pub fn key_binding(&mut self, key: KeyEvent, token_info: &TokenInfo) {
-        match key.code {
-            KeyCode::F(1) => {
-                if !self.form.popup.active & !self.form.popup.active {
-                    self.help.popup.active = true;
-                    self.form.popup.active = false;
-                }
+fn edit_series(
+    calendar: &dyn CalendarApi,
+    calendar_id: &str,
+    instance: &Event,
+    scope: Scope,
+    mut patch: Map<String, Value>,
+    following: Option<Event>,
+) -> Result<(), ApiError> {
+    let Some(series_id) = instance.recurring_event_id.as_deref().filter(|_| scope != Scope::This) else {
+        return calendar.patch_event(calendar_id, &instance.id, &Value::Object(patch));
+    };
+    let series = calendar.get_event(calendar_id, series_id)?;
+
+    match (following, following_start(instance, &series)) {
+        (Some(following), Some(start)) => {
+            let attendees = following.attendees
+                .into_iter()
+                .map(|attendee| match series.attendees.iter().find(|existing| existing.email == attendee.email) {
+                    Some(existing) => existing.clone(),
+                    None => attendee,
+                })
+                .collect();
+            let recurrence = match (recurrence::count(&series.recurrence), start.in_zone(&Utc)) {
+                (Some(_), Some(time_max)) => {
+                    let kept = calendar.list_instances(calendar_id, series_id, time_max)?.len();
+                    recurrence::remaining(&series.recurrence, kept as u32)
+                },
+                _ => series.recurrence.clone(),
+            };
+            let following = Event {
+                attendees,
+                recurrence,
+                ..following
+            };
+            calendar.insert_event(calendar_id, &following)?;
+
+            let recurrence = recurrence::end_before(&series.recurrence, &start);
+            calendar.patch_event(calendar_id, series_id, &json!({ "recurrence": recurrence }))
+        },
+        _ => {
+            shift_times(&mut patch, instance, &series);
+            calendar.patch_event(calendar_id, series_id, &Value::Object(patch))
+        },
+    }
+}
+
+fn delete_series(calendar: &dyn CalendarApi, calendar_id: &str, instance: &Event, scope: Scope) -> Result<(), ApiError> {
+    let Some(series_id) = instance.recurring_event_id.as_deref().filter(|_| scope != Scope::This) else {
+        return calendar.delete_event(calendar_id, &instance.id);
+    };
+
+    if scope == Scope::Following {
+        let series = calendar.get_event(calendar_id, series_id)?;
+
+        if let Some(start) = following_start(instance, &series) {
+            let recurrence = recurrence::end_before(&series.recurrence, &start);
+            return calendar.patch_event(calendar_id, series_id, &json!({ "recurrence": recurrence }));
+        }
+    }
+
+    calendar.delete_event(calendar_id, series_id)
+}
+
+impl<'a> TabApp for Schedules<'a> {
+    fn title(&self) -> &str {
+        APP_TITLE
+    }
+
+    fn pane(&mut self) -> &mut Pane {
+        &mut self.pane
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let pane = self.pane.render(frame, area);
+
+        match self.view {
+            View::List => {
+                let groups = self.groups();
+                self.list.render_grouped(frame, pane, groups);
             },
-            KeyCode::F(2) => { 
-                if !self.help.popup.active & !self.form.popup.active {
-                    self.form.popup.title = "Add Schedule".to_string();
-                    self.form.mode = FormMode::New;
-                    self.help.popup.active = false;
-                    self.form.popup.active = true;
-                    self.form.all_clear();
-                    self.form.active_summary();
-                }
+            View::Week => {
+                let events = self.day_events();
+                let week = WeekView {
+                    start: week_view::week_start(self.start_date),
+                    cursor: self.start_date,
+                    events: &events,
+                };
+                frame.render_widget(week, pane);
             },
-            KeyCode::F(3) => { 
-                if !self.help.popup.active & !self.form.popup.active && self.schedules.len() > 0 {
-                    self.form.popup.title = "Edit Schedule".to_string();
-                    self.form.mode = FormMode::Edit;
-                    self.help.popup.active = false;
-                    self.form.popup.active = true;
-                    self.form.all_clear();
-                    self.form.active_summary();
-
-                    let schedule = &self.schedules[self.list.index];
-                    self.form.summary.text = schedule.summary.to_string();
-                    self.form.start.text = schedule.start.to_string();
-                    self.form.end.text = schedule.end.to_string();
-                    self.form.description.text = schedule.description.to_string();
-                }
+            View::Month => {
+                let events = self.day_events();
+                let month = MonthView {
+                    cursor: self.start_date,
+                    events: &events,
+                };
+                frame.render_widget(month, pane);
             },
-            KeyCode::F(12) => {
-                if self.form.popup.active {
-                    match self.form.mode {
-                        FormMode::New => self.add(token_info),
-                        FormMode::Edit => self.edit(token_info),
-                    }
-                }
-            }
-            KeyCode::Enter => {
-                if !self.help.popup.active & !self.form.popup.active {
-                    self.open();
-                }
+        }
+    }
+
+    fn render_popup(&mut self, frame: &mut Frame) {
+        self.form.render(frame);
+        self.jump.render(frame);
+        self.picker.render(frame);
+        self.scope.render(frame);
+        self.detail.render(frame);
+        self.rsvp.render(frame);
+        self.finder.render(frame);
+        self.date_picker.render(frame);
+    }
+
+    fn on_action(&mut self, action: Action) {
+        match action {
+            Action::Add if !self.has_open_popup() => self.open_add(),
+            Action::Edit if !self.has_open_popup() && self.selected().is_some() => {
+                self.hold_target();
+                let Some(schedule) = self.target.clone() else {
+                    return;
+                };
+
+                self.form.popup.title = "Edit Schedule".to_string();
+                self.form.mode = FormMode::Edit;
+                self.form.popup.active = true;
+                self.form.all_clear();
+                self.form.set_time_zone(self.time_zone.name());
+                self.form.active_summary();
+
+                self.form.calendars = vec![self.calendar_label(&schedule)];
+
+                let event = &schedule.event;
+                self.form.summary.text = event.summary.clone().unwrap_or_default();
+                let [start, end] = time_texts(event, self.time_zone);
+                self.form.set_all_day(is_all_day(event));
+                self.form.start.text = start;
+                self.form.end.text = end;
+                self.form.attendees.text = event.attendees
+                    .iter()
+                    .map(|attendee| attendee.email.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(", ");
+                self.form.location.text = event.location.clone().unwrap_or_default();
+                self.form.set_meet(has_meet(event));
+                self.form.set_color_id(event.color_id.as_deref());
+                self.form.set_visibility(event.visibility.as_deref());
+                self.form.reminders.text = reminder::format(&reminder_overrides(event));
+                self.form.description.text = event.description.clone().unwrap_or_default();
+                self.update_previews();
             },
-            KeyCode::Char('D') => self.delete(token_info),
-            _ => {
-                if self.form.popup.active {
-                    self.form.key_binding(key);
-                } else if !self.help.popup.active {
-                    self.list.key_binding(key);
+            Action::Submit if self.date_picker.popup.active => self.pick_date(),
+            Action::Submit if self.jump.popup.active => self.jump_to(),
+            Action::Submit if self.picker.popup.active => self.apply_calendars(),
+            Action::Submit if self.scope.popup.active => self.apply_scope(),
+            Action::Submit if self.rsvp.popup.active => self.respond(),
+            Action::NextField if self.rsvp.popup.active => self.rsvp.next(),
+            Action::Submit if self.finder.popup.active && self.finder.slots_pane.active => self.pick_slot(),
+            Action::Submit if self.finder.popup.active => self.find_time(),
+            Action::NextField if self.finder.popup.active => self.finder.next(),
+            Action::Submit if self.form.popup.active => {
+                match self.form.mode {
+                    FormMode::New => self.add(),
+                    FormMode::Edit => self.submit_edit(),
                 }
+            },
+            Action::NextField if self.date_picker.popup.active => self.date_picker.next(),
+            Action::PickDate if self.form.popup.active && !self.date_picker.popup.active => {
+                self.open_date_picker();
+            },
+            Action::NextField if self.form.popup.active => {
+                self.form.next();
+            },
+            Action::Open if !self.has_open_popup() && self.selected().is_some() => {
+                self.show_detail();
+            },
+            Action::OpenBrowser if (!self.has_open_popup() || self.detail.popup.active) && self.selected().is_some() => {
+                self.open();
+            },
+            Action::Up if self.detail.popup.active => self.detail.scroll(-1),
+            Action::Down if self.detail.popup.active => self.detail.scroll(1),
+            Action::Top if self.detail.popup.active => self.detail.top(),
+            Action::Bottom if self.detail.popup.active => self.detail.bottom(),
+            Action::Delete if !self.has_open_popup() && self.selected().is_some() => {
+                self.submit_delete();
+            },
+            Action::PrevDay if !self.has_open_popup() => self.shift(-1),
+            Action::NextDay if !self.has_open_popup() => self.shift(1),
+            Action::PrevWeek if !self.has_open_popup() => self.shift(-7),
+            Action::NextWeek if !self.has_open_popup() => self.shift(7),
+            Action::Today if !self.has_open_popup() => self.go_to(today(self.time_zone)),
+            Action::GoToDate if !self.has_open_popup() => self.jump.open(),
+            Action::ToggleView if !self.has_open_popup() => self.toggle_view(),
+            Action::Calendars if !self.has_open_popup() => self.open_calendars(),
+            Action::Rsvp if !self.has_open_popup() && self.selected().is_some() => self.open_rsvp(),
+            Action::Invitations if !self.has_open_popup() => self.toggle_pending(),
+            Action::FindTime if !self.has_open_popup() => self.finder.open(today(self.time_zone), self.time_zone),
+            Action::Refresh => self.refresh(),
+            Action::Up => self.move_selection(-1),
+            Action::Down => self.move_selection(1),
+            Action::Left => self.move_day(-1),
+            Action::Right => self.move_day(1),
+            Action::Top => self.list.top(),
+            Action::Bottom => self.list.bottom(),
+            _ => {},
+        }
+    }
+
+    fn on_input(&mut self, key: KeyEvent) {
+        if self.date_picker.popup.active {
+            match key.code {
+                KeyCode::Enter => self.pick_date(),
+                _ => self.date_picker.key_binding(key),
+            }
+        } else if self.detail.popup.active {
+            self.detail.key_binding(key);
+        } else if self.jump.popup.active {
+            self.jump.key_binding(key);
+        } else if self.picker.popup.active {
+            self.picker.key_binding(key);
+        } else if self.scope.popup.active {
+            self.scope.key_binding(key);
+        } else if self.rsvp.popup.active {
+            self.rsvp.key_binding(key);
+        } else if self.finder.popup.active {
+            self.finder.key_binding(key);
+        } else if self.form.popup.active {
+            self.form.key_binding(key);
+            self.update_previews();
+        }
+    }
+
+    fn help(&self) -> Vec<HelpSection> {
+        vec![
+            HelpSection::new("Select Schedule", Context::List, vec![
+                HelpItem::Action(Action::Open, "Open Schedule Detail"),
+                HelpItem::Action(Action::OpenBrowser, "Open Schedule in Browser"),
+                HelpItem::Action(Action::Up, "Focus Move Up"),
+                HelpItem::Action(Action::Down, "Focus Move Down"),
+                HelpItem::Action(Action::Left, "Focus Previous Day in Week/Month View"),
+                HelpItem::Action(Action::Right, "Focus Next Day in Week/Month View"),
+                HelpItem::Action(Action::Top, "Focus Move Top"),
+                HelpItem::Action(Action::Bottom, "Focus Move Bottom"),
+                HelpItem::Action(Action::Delete, "Execute Delete Schedule"),
+                HelpItem::Action(Action::Add, "Open Add Schedule"),
+                HelpItem::Action(Action::Edit, "Open Edit Schedule"),
+                HelpItem::Action(Action::PrevDay, "Show Previous Day"),
+                HelpItem::Action(Action::NextDay, "Show Next Day"),
+                HelpItem::Action(Action::PrevWeek, "Show Previous Week"),
+                HelpItem::Action(Action::NextWeek, "Show Next Week"),
+                HelpItem::Action(Action::Today, "Show Today"),
+                HelpItem::Action(Action::GoToDate, "Open Go To Date"),
+                HelpItem::Action(Action::ToggleView, "Switch List/Week/Month View"),
+                HelpItem::Action(Action::Calendars, "Open Calendars"),
+                HelpItem::Action(Action::Rsvp, "Open Reply to Invitation"),
+                HelpItem::Action(Action::Invitations, "Toggle Only Invitations Needing a Response"),
+                HelpItem::Action(Action::FindTime, "Open Find a Time"),
+            ]),
+            HelpSection::new("Add Schedule", Context::Form, vec![
+                HelpItem::Action(Action::NextField, "Move Input Form"),
+                HelpItem::Action(Action::Submit, "Execute Add Schedule"),
+                HelpItem::Action(Action::Quit, "Close Add Schedule"),
+                HelpItem::Note("Start/End Format", "tomorrow 3pm, next mon 10:00, +2h, fri, yyyy-mm-dd hh:mm"),
+                HelpItem::Note("End Duration", "1h30m, 2d (from Start)"),
+                HelpItem::Note("All Day", "Toggle with Space, End is the last day"),
+                HelpItem::Action(Action::PickDate, "Open Date Picker on Start/End"),
+                HelpItem::Note("Calendar", "Select with Left/Right"),
+                HelpItem::Note("Attendees", "Comma separated emails"),
+                HelpItem::Note("Meet Link", "Toggle with Space"),
+                HelpItem::Note("Color", "Select with Left/Right"),
+                HelpItem::Note("Visibility", "Select with Left/Right"),
+                HelpItem::Note("Reminders Format", "popup 10m, email 1d (up to 5, empty for default)"),
+                HelpItem::Note("Repeat", "Select with Left/Right, toggle Repeat On days with Space"),
+                HelpItem::Note("Ends Format", "yyyy-mm-dd, a count, or empty for never"),
+            ]),
+            HelpSection::new("Edit Schedule", Context::Form, vec![
+                HelpItem::Action(Action::NextField, "Move Input Form"),
+                HelpItem::Action(Action::Submit, "Execute Edit Schedule"),
+                HelpItem::Action(Action::Quit, "Close Edit Schedule"),
+                HelpItem::Note("Start/End Format", "tomorrow 3pm, next mon 10:00, +2h, fri, yyyy-mm-dd hh:mm"),
+                HelpItem::Note("End Duration", "1h30m, 2d (from Start)"),
+                HelpItem::Note("All Day", "Toggle with Space, End is the last day"),
+                HelpItem::Action(Action::PickDate, "Open Date Picker on Start/End"),
+                HelpItem::Note("Attendees", "Comma separated emails"),
+                HelpItem::Note("Meet Link", "Toggle with Space"),
+                HelpItem::Note("Color", "Select with Left/Right"),
+                HelpItem::Note("Visibility", "Select with Left/Right"),
+                HelpItem::Note("Reminders Format", "popup 10m, email 1d (up to 5, empty for default)"),
+            ]),
+            HelpSection::new("Schedule Detail", Context::List, vec![
+                HelpItem::Action(Action::Up, "Scroll Up"),
+                HelpItem::Action(Action::Down, "Scroll Down"),
+                HelpItem::Note("PageUp/PageDown", "Scroll by Page"),
+                HelpItem::Action(Action::OpenBrowser, "Open Schedule in Browser"),
+                HelpItem::Action(Action::Quit, "Close Schedule Detail"),
+            ]),
+            HelpSection::new("Reply to Invitation", Context::Form, vec![
+                HelpItem::Note("Left/Right", "Choose Yes/Maybe/No"),
+                HelpItem::Action(Action::NextField, "Move to Note"),
+                HelpItem::Action(Action::Submit, "Execute Reply"),
+                HelpItem::Action(Action::Quit, "Close Reply to Invitation"),
+            ]),
+            HelpSection::new("Find a Time", Context::Form, vec![
+                HelpItem::Action(Action::NextField, "Move Input Form / Free Slots"),
+                HelpItem::Action(Action::Submit, "Search Free Slots, or Add Schedule in the Selected Slot"),
+                HelpItem::Action(Action::Quit, "Close Find a Time"),
+                HelpItem::Note("Free Slots", "Select with Up/Down, weekdays 09:00-18:00"),
+                HelpItem::Note("Duration Format", "30m, 1h30m"),
+            ]),
+            HelpSection::new("Pick Date", Context::Form, vec![
+                HelpItem::Note("Arrows", "Move Day/Week, Up/Down change Hour/Minute"),
+                HelpItem::Note("PageUp/PageDown", "Move Month"),
+                HelpItem::Action(Action::NextField, "Move Date/Hour/Minute"),
+                HelpItem::Action(Action::Submit, "Apply Date (also Enter)"),
+                HelpItem::Action(Action::Quit, "Close Date Picker"),
+            ]),
+            HelpSection::new("Go To Date", Context::Form, vec![
+                HelpItem::Action(Action::Submit, "Execute Go To Date"),
+                HelpItem::Action(Action::Quit, "Close Go To Date"),
+                HelpItem::Note("Date Format", "yyyy-mm-dd"),
+            ]),
+            HelpSection::new("Recurring Schedule", Context::Form, vec![
+                HelpItem::Note("Up/Down", "Choose This/Following/All Events"),
+                HelpItem::Action(Action::Submit, "Apply to Chosen Events"),
+                HelpItem::Action(Action::Quit, "Cancel"),
+            ]),
+            HelpSection::new("Calendars", Context::Form, vec![
+                HelpItem::Note("Space", "Toggle Calendar"),
+                HelpItem::Action(Action::Submit, "Apply Calendars"),
+                HelpItem::Action(Action::Quit, "Close Calendars"),
+            ]),
+        ]
+    }
+
+    fn has_open_popup(&self) -> bool {
+        self.form.popup.active
+            || self.jump.popup.active
+            || self.picker.popup.active
+            || self.scope.popup.active
+            || self.detail.popup.active
+            || self.rsvp.popup.active
+            || self.finder.popup.active
+    }
+
+    fn context(&self) -> Context {
+        if self.has_open_popup() && !self.detail.popup.active {
+            Context::Form
+        } else {
+            Context::List
+        }
+    }
+
+    fn close_popup(&mut self) {
+        if self.date_picker.popup.active {
+            self.date_picker.popup.active = false;
+            return;
+        }
+
+        self.form.popup.active = false;
+        self.jump.popup.active = false;
+        self.picker.popup.active = false;
+        self.scope.popup.active = false;
+        self.detail.popup.active = false;
+        self.rsvp.popup.active = false;
+        self.finder.popup.active = false;
+    }
+
+    fn refresh(&mut self) {
+        self.interval.reset();
+        if self.zone_worker.is_busy() {
+            return;
+        }
+        if self.worker.is_busy() {
+            self.stale = true;
+            return;
+        }
+
+        self.stale = false;
+        let reload = self.reload();
+
+        self.worker.spawn(reload);
+    }
+
+    fn set_accounts(&mut self, accounts: &Accounts) {
+        self.accounts = accounts.clone();
+        self.load_time_zone();
+        self.refresh();
+    }
+
+    fn tick(&mut self) {
+        while let Some(result) = self.zone_worker.try_recv() {
+            match result {
+                Ok(calendar) => self.set_time_zone(calendar),
+                Err(err) => self.notifier.error(err.to_string()),
             }
+            self.refresh();
+        }
+
+        while let Some(result) = self.busy_worker.try_recv() {
+            match result {
+                Ok((search, availability)) => self.finder.set_result(search, availability),
+                Err(err) => self.notifier.error(err.to_string()),
+            }
+        }
+        self.finder.slots_pane.loading = self.busy_worker.is_busy();
+
+        while let Some(result) = self.worker.try_recv() {
+            match result {
+                Ok((range, _)) if range != self.range() => {},
+                Ok((_, (calendars, schedules, errors))) => {
+                    for err in errors {
+                        self.notifier.error(err.to_string());
+                    }
+                    self.calendars = calendars;
+                    self.loaded = schedules;
+                    self.filter_schedules();
+                    self.pane.synced = Some(Local::now());
+                },
+                Err(err) => self.notifier.error(err.to_string()),
+            }
+        }
+
+        self.pane.loading = self.worker.is_busy() || self.zone_worker.is_busy();
+
+        if (self.stale || self.interval.is_due()) && !self.pane.loading {
+            self.refresh();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::app::config::Google;
+    use crate::app::google::{calendar::HttpCalendar, stub::{Request, StubServer}};
+
+    const SERIES_PATH: &str = "/calendars/primary/events/series";
+
+    fn event(value: Value) -> Event {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn splits_following_events_with_every_form_field() {
+        let server = StubServer::start(|request| match (request.method.as_str(), request.path()) {
+            ("GET", SERIES_PATH) => (200, json!({
+                "id": "series",
+                "summary": "Standup",
+                "location": "Room 1",
+                "start": { "dateTime": "2024-12-02T00:00:00Z" },
+                "end": { "dateTime": "2024-12-02T00:15:00Z" },
+                "recurrence": ["RRULE:FREQ=DAILY"],
+                "attendees": [{ "email": "boss@example.com", "responseStatus": "accepted" }],
+            }).to_string()),
+            _ => (200, "{}".to_string()),
+        });
+        let calendar = HttpCalendar::new(&server.url, server.tokens());
+
+        let instance = event(json!({
+            "id": "series_20241205",
+            "recurringEventId": "series",
+            "originalStartTime": { "dateTime": "2024-12-05T00:00:00Z" },
+            "start": { "dateTime": "2024-12-05T00:00:00Z" },
+        }));
+        let following = event(json!({
+            "summary": "Standup",
+            "location": "Room 2",
+            "colorId": "3",
+            "visibility": "private",
+            "start": { "dateTime": "2024-12-05T01:00:00Z" },
+            "end": { "dateTime": "2024-12-05T01:15:00Z" },
+            "attendees": [{ "email": "boss@example.com" }, { "email": "new@example.com" }],
+            "reminders": { "useDefault": false, "overrides": [{ "method": "popup", "minutes": 5 }] },
+            "conferenceData": { "createRequest": { "requestId": "1", "conferenceSolutionKey": { "type": "hangoutsMeet" } } },
+        }));
+
+        edit_series(&calendar, calendar::PRIMARY, &instance, Scope::Following, Map::new(), Some(following)).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].json(), json!({
+            "summary": "Standup",
+            "location": "Room 2",
+            "colorId": "3",
+            "visibility": "private",
+            "conferenceData": { "createRequest": { "requestId": "1", "conferenceSolutionKey": { "type": "hangoutsMeet" } } },
+            "attendees": [
+                { "email": "boss@example.com", "responseStatus": "accepted" },
+                { "email": "new@example.com" },
+            ],
+            "reminders": { "useDefault": false, "overrides": [{ "method": "popup", "minutes": 5 }] },
+            "start": { "dateTime": "2024-12-05T01:00:00Z" },
+            "end": { "dateTime": "2024-12-05T01:15:00Z" },
+            "recurrence": ["RRULE:FREQ=DAILY"],
+        }));
+        assert_eq!(requests[2].method, "PATCH");
+        assert_eq!(requests[2].path(), SERIES_PATH);
+        assert_eq!(requests[2].json(), json!({ "recurrence": ["RRULE:FREQ=DAILY;UNTIL=20241204T235959Z"] }));
+    }
+
+    #[test]
+    fn continues_counted_series_with_the_remaining_occurrences() {
+        let server = StubServer::start(|request| match (request.method.as_str(), request.path()) {
+            ("GET", SERIES_PATH) => (200, json!({
+                "id": "series",
+                "start": { "date": "2024-12-02" },
+                "end": { "date": "2024-12-03" },
+                "recurrence": ["RRULE:FREQ=DAILY;COUNT=5"],
+            }).to_string()),
+            ("GET", _) => (200, json!({ "items": [{ "id": "1" }, { "id": "2" }, { "id": "3" }] }).to_string()),
+            _ => (200, "{}".to_string()),
+        });
+        let calendar = HttpCalendar::new(&server.url, server.tokens());
+
+        let instance = event(json!({
+            "id": "series_20241205",
+            "recurringEventId": "series",
+            "start": { "date": "2024-12-05" },
+        }));
+        let following = event(json!({
+            "summary": "Off",
+            "start": { "date": "2024-12-05" },
+            "end": { "date": "2024-12-06" },
+        }));
+
+        edit_series(&calendar, calendar::PRIMARY, &instance, Scope::Following, Map::new(), Some(following)).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[1].path(), format!("{}/instances", SERIES_PATH));
+        assert_eq!(requests[1].query("timeMax").as_deref(), Some("2024-12-05T00:00:00Z"));
+        assert_eq!(requests[1].query("showDeleted").as_deref(), Some("true"));
+        assert_eq!(requests[2].json()["recurrence"], json!(["RRULE:FREQ=DAILY;COUNT=2"]));
+        assert_eq!(requests[3].json(), json!({ "recurrence": ["RRULE:FREQ=DAILY;UNTIL=20241204"] }));
+    }
+
+    fn schedule(id: &str, summary: &str) -> Schedule {
+        Schedule {
+            event: event(json!({
+                "id": id,
+                "summary": summary,
+                "start": { "dateTime": "2024-12-02T09:00:00Z" },
+                "end": { "dateTime": "2024-12-02T10:00:00Z" },
+            })),
+            account: "work".to_string(),
+            calendar_id: calendar::PRIMARY.to_string(),
+            color: None,
+        }
+    }
+
+    #[test]
+    fn edits_the_event_opened_in_the_form_after_a_reload() {
+        let server = StubServer::start(|_| (200, "{}".to_string()));
+        let google = Google { calendar_url: server.url.clone(), tasks_url: server.url.clone() };
+        let accounts = Accounts::new(vec![Account::new("work", server.tokens(), &google)], false);
+        let config = Config { time_zone: Some(Tz::UTC), ..Config::default() };
+        let (notifier, _receiver) = Notifier::new();
+
+        let mut app = Schedules::new(&config, &accounts, &notifier);
+        app.schedules = vec![schedule("standup", "Standup")];
+        app.on_action(Action::Edit);
+
+        app.schedules = vec![schedule("review", "Review")];
+        app.form.summary.set("Daily standup");
+        app.on_action(Action::Submit);
+        while app.worker.is_busy() {
+            std::thread::sleep(std::time::Duration::from_millis(10));
         }
+
+        let patches: Vec<Request> = server.requests().into_iter().filter(|request| request.method == "PATCH").collect();
+        assert_eq!(patches.len(), 1);
+        assert_eq!(patches[0].path(), "/calendars/primary/events/standup");
+        assert_eq!(patches[0].json()["summary"], "Daily standup");
+    }
+
+    #[test]
+    fn reads_the_calendars_that_load_and_reports_the_rest() {
+        let server = StubServer::start(|request| match request.path() {
+            "/users/me/calendarList" => (200, json!({ "items": [
+                { "id": "primary", "summary": "Me", "primary": true },
+                { "id": "team", "summary": "Team", "selected": true },
+            ] }).to_string()),
+            "/calendars/primary/events" => (200, json!({ "items": [{ "id": "standup" }] }).to_string()),
+            _ => (500, json!({ "error": { "code": 500, "message": "Backend Error" } }).to_string()),
+        });
+        let google = Google { calendar_url: server.url.clone(), tasks_url: server.url.clone() };
+        let account = Account::new("work", server.tokens(), &google);
+        let range = ["2024-12-02T00:00:00Z".parse().unwrap(), "2024-12-03T00:00:00Z".parse().unwrap()];
+
+        let (calendars, schedules, errors) = Schedules::read(&account, range, &HashMap::new()).unwrap();
+
+        assert_eq!(calendars.len(), 2);
+        assert_eq!(schedules.len(), 1);
+        assert_eq!(schedules[0].event.id, "standup");
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(&errors[0], ScheduleError::ReadCalendar { calendar, .. } if calendar == "[work] Team"));
     }
 }
@@ -0,0 +1,62 @@
+use ratatui::{
+    crossterm::event::KeyEvent,
+    layout::Margin,
+    Frame,
+};
+
+use crate::app::schedule::recurrence::{Scope, SCOPES};
+use crate::app::ui::{
+    checkbox::CheckBoxes,
+    popup::Popup,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pending {
+    Edit,
+    Delete,
+}
+
+#[derive(Debug)]
+pub struct ScopePrompt {
+    pub popup: Popup,
+    pub scopes: CheckBoxes,
+    pub pending: Option<Pending>,
+}
+
+impl ScopePrompt {
+    pub fn new() -> Self {
+        Self {
+            popup: Popup::new("Recurring Schedule"),
+            scopes: CheckBoxes::new(),
+            pending: None,
+        }
+    }
+
+    pub fn open(&mut self, pending: Pending) {
+        self.popup.title = match pending {
+            Pending::Edit => "Edit Recurring Schedule".to_string(),
+            Pending::Delete => "Delete Recurring Schedule".to_string(),
+        };
+        self.popup.active = true;
+        self.pending = Some(pending);
+        self.scopes.clear();
+    }
+
+    pub fn close(&mut self) -> Option<(Pending, Scope)> {
+        self.popup.active = false;
+
+        self.pending.take().map(|pending| (pending, Scope::from_index(self.scopes.index)))
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        if self.popup.active {
+            let area = self.popup.render(frame, [40, 30]);
+
+            self.scopes.render(frame, area.inner(Margin::new(2, 1)), SCOPES.to_vec(), true);
+        }
+    }
+
+    pub fn key_binding(&mut self, key: KeyEvent) {
+        self.scopes.key_binding(key);
+    }
+}
@@ -0,0 +1,46 @@
+use ratatui::{
+    crossterm::event::KeyEvent,
+    layout::Rect,
+    Frame,
+};
+
+use crate::app::google::account::Accounts;
+use crate::app::keymap::{Action, Context};
+use crate::app::ui::{
+    help::HelpSection,
+    pane::Pane,
+};
+
+pub trait TabApp {
+    fn title(&self) -> &str;
+
+    fn pane(&mut self) -> &mut Pane;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect);
+
+    fn render_popup(&mut self, frame: &mut Frame);
+
+    fn on_action(&mut self, action: Action);
+
+    fn on_input(&mut self, key: KeyEvent);
+
+    fn help(&self) -> Vec<HelpSection>;
+
+    fn has_open_popup(&self) -> bool;
+
+    fn close_popup(&mut self);
+
+    fn context(&self) -> Context {
+        if self.has_open_popup() {
+            Context::Form
+        } else {
+            Context::List
+        }
+    }
+
+    fn refresh(&mut self);
+
+    fn set_accounts(&mut self, _accounts: &Accounts) {}
+
+    fn tick(&mut self);
+}
@@ -110,6 +110,16 @@ impl Form {
         self.due.active = true;
     }
 
+    pub fn next(&mut self) {
+        if self.title.active {
+            self.active_notes();
+        } else if self.notes.active {
+            self.active_due();
+        } else if self.due.active {
+            self.active_title();
+        }
+    }
+
     pub fn key_binding(&mut self, key: KeyEvent) {
         if self.title.active {
             self.title.key_binding(key);
@@ -1,38 +1,95 @@
-use std::process::Command;
-
-use reqwest::blocking::Client;
-use chrono::{Utc, FixedOffset, TimeZone};
+use chrono::{DateTime, Local, NaiveTime, Utc};
+use chrono_tz::Tz;
 use ratatui::{
-    crossterm::event::{KeyCode, KeyEvent}, layout::Rect, widgets::block::Title, Frame
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::Rect,
+    Frame,
 };
-use serde::Serialize;
-use serde_json::json;
+use serde_json::{json, Map, Value};
 
+use crate::app::config::{self, Browser, Config};
+use crate::app::date_parser;
 use crate::app::task::form::{Form, Mode as FormMode};
+use crate::app::keymap::{Action, Context};
+use crate::app::tab::TabApp;
 use crate::app::ui::{
-    help::Help,
+    date_picker::DatePicker,
+    help::{HelpItem, HelpSection},
     select_list::SelectList,
-    pane::Pane,
+    pane::{Pane, Preview},
+};
+use crate::app::interval::Interval;
+use crate::app::notification::Notifier;
+use crate::app::worker::Worker;
+use crate::app::google::{
+    api::ApiError,
+    account::{Account, Accounts},
+    tasks,
 };
-use crate::app::google::authentication::TokenInfo;
 
-const BASE_API: &str = "https://tasks.googleapis.com/tasks/v1/lists/@default/tasks";
-const BROWSER_PATH: &str = "/mnt/c/Program Files/BraveSoftware/Brave-Browser/Application/brave.exe";
 const APP_TITLE: &str = "Task";
-const HELP_TITLE: &str = "Help Schedule";
+const DUE_FORMAT: &str = "%Y-%m-%d";
+const PREVIEW_FORMAT: &str = "%a %Y-%m-%d";
+const INVALID_PREVIEW: &str = "Unrecognized date";
+
+#[derive(Debug, thiserror::Error)]
+pub enum TaskError {
+    #[error("Failed to load tasks: {0}")]
+    Read(ApiError),
+    #[error("Failed to add task: {0}")]
+    Add(ApiError),
+    #[error("Failed to edit task: {0}")]
+    Edit(ApiError),
+    #[error("Failed to complete task: {0}")]
+    Complete(ApiError),
+    #[error("Failed to delete task: {0}")]
+    Delete(ApiError),
+    #[error("Failed to open tasks: {0}")]
+    Open(std::io::Error),
+    #[error("Invalid due \"{0}\", expected a date like tomorrow, fri or yyyy-mm-dd")]
+    InvalidDue(String),
+    #[error("Task no longer exists")]
+    Missing,
+}
 
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
 pub struct Task {
-    pub id: String,
-    pub title: String,
-    pub notes: String,
-    pub due: String,
-    pub status: String,
+    pub item: tasks::Task,
+    pub account: String,
 }
 
 impl Task {
-    fn value(&self) -> String {
-        format!("{}\n  {}\n  {}", self.title, self.notes, self.due)
+    fn value(&self, badge: bool) -> String {
+        let notes = self.item.notes.as_deref().unwrap_or_default();
+        let due = format_due(self.item.due);
+
+        if badge {
+            format!("[{}] {}\n  {}\n  {}", self.account, self.item.title, notes, due)
+        } else {
+            format!("{}\n  {}\n  {}", self.item.title, notes, due)
+        }
+    }
+}
+
+fn format_due(due: Option<DateTime<Utc>>) -> String {
+    due.map(|due| due.format(DUE_FORMAT).to_string()).unwrap_or_default()
+}
+
+fn parse_due(text: &str, time_zone: Tz) -> Result<Option<DateTime<Utc>>, TaskError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(None);
+    }
+
+    date_parser::parse(text, &Utc::now().with_timezone(&time_zone))
+        .map(|due| Some(due.date().and_time(NaiveTime::MIN).and_utc()))
+        .ok_or_else(|| TaskError::InvalidDue(text.to_string()))
+}
+
+fn preview_due(text: &str, time_zone: Tz) -> Option<Preview> {
+    match parse_due(text, time_zone) {
+        Ok(due) => due.map(|due| Preview::Valid(due.format(PREVIEW_FORMAT).to_string())),
+        Err(_) => Some(Preview::Invalid(INVALID_PREVIEW.to_string())),
     }
 }
 
@@ -42,206 +99,449 @@ pub struct Tasks<'a> {
     pub tasks: Vec<Task>,
     pub list: SelectList<'a>,
     pub form: Form,
-    pub help: Help,
+    pub date_picker: DatePicker,
+    accounts: Accounts,
+    browser: Browser,
+    time_zone: Tz,
+    editing: Option<(String, String)>,
+    stale: bool,
+    worker: Worker<Result<Vec<Task>, TaskError>>,
+    interval: Interval,
+    notifier: Notifier,
 }
 
 impl<'a> Tasks<'a> {
-    pub fn new(token_info: &TokenInfo) -> Self {
+    pub fn new(config: &Config, accounts: &Accounts, notifier: &Notifier) -> Self {
         Self {
             pane: Pane::new(APP_TITLE),
-            tasks: Self::read(token_info),
+            tasks: Vec::new(),
             list: SelectList::new(),
-            form: Form::new() ,
-            help: Help::new(HELP_TITLE),
+            form: Form::new(),
+            date_picker: DatePicker::new(),
+            accounts: accounts.clone(),
+            browser: config.browser.clone(),
+            time_zone: config.time_zone.unwrap_or_else(config::system_time_zone),
+            editing: None,
+            stale: false,
+            worker: Worker::new(),
+            interval: Interval::from_secs(config.refresh.task),
+            notifier: notifier.clone(),
         }
     }
 
-    pub fn read(token_info: &TokenInfo) -> Vec<Task> {
-        let client = Client::new();
+    pub fn read_all(accounts: &[Account]) -> Result<Vec<Task>, ApiError> {
+        let mut tasks = Vec::new();
+        for account in accounts {
+            tasks.extend(Self::read(account)?);
+        }
+        if accounts.len() > 1 {
+            tasks.sort_by_key(|task| task.item.due);
+        }
 
-        let response = client
-            .get(BASE_API)
-            .bearer_auth(&token_info.access_token)
-            .query(&[("showCompleted", "false")])
-            .send()
-            .unwrap();
+        Ok(tasks)
+    }
 
-        let mut tasks = Vec::<Task>::new();
-        if response.status().is_success() {
-            let events: serde_json::Value = response.json().unwrap();
-            if let Some(items) = events["items"].as_array() {
-                if !items.is_empty() {
-                    for item in items {
-                        let id = item["id"].to_string().replace("\"", "");
-                        let title = item["title"].to_string().replace("\"", "");
-                        let notes = item["notes"].to_string().replace("\"", "");
-                        let due = item["due"].to_string().replace("\"", "");
-                        let status = item["status"].to_string().replace("\"", "");
+    pub fn read(account: &Account) -> Result<Vec<Task>, ApiError> {
+        let items = account.tasks.list_tasks()?;
 
-                        let task: Task = Task { id, title, notes, due, status };
+        Ok(items
+            .into_iter()
+            .map(|item| Task { item, account: account.name.clone() })
+            .collect())
+    }
 
-                        tasks.push(task);
-                    }
-                }
-            }
-        };
+    fn form_task(&self) -> Result<tasks::Task, TaskError> {
+        let notes = self.form.notes.text.to_string();
 
-        tasks
+        Ok(tasks::Task {
+            title: self.form.title.text.to_string(),
+            notes: (!notes.is_empty()).then_some(notes),
+            due: parse_due(&self.form.due.text, self.time_zone)?,
+            ..tasks::Task::default()
+        })
     }
 
-    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
-        let pane = self.pane.render(frame, area);
+    fn form_patch(&self, item: &tasks::Task) -> Result<Map<String, Value>, TaskError> {
+        let mut patch = Map::new();
 
-        let list = self.tasks.iter().map(|tasks| {
-            tasks.value().clone()
-        })
-        .collect();
+        if self.form.title.text != item.title {
+            patch.insert("title".to_string(), json!(self.form.title.text));
+        }
+        if self.form.notes.text != item.notes.as_deref().unwrap_or_default() {
+            patch.insert("notes".to_string(), json!(self.form.notes.text));
+        }
+        if self.form.due.text != format_due(item.due) {
+            patch.insert("due".to_string(), json!(parse_due(&self.form.due.text, self.time_zone)?));
+        }
 
-        self.list.render(frame, pane, list);
+        Ok(patch)
+    }
+
+    fn pick_due(&mut self) {
+        self.date_picker.popup.active = false;
+        self.form.due.set(&self.date_picker.date.format(DUE_FORMAT).to_string());
+        self.form.due_pane.preview = preview_due(&self.form.due.text, self.time_zone);
     }
 
-    pub fn add(&mut self, token_info: &TokenInfo) {
-        let client = Client::new();
+    pub fn add(&mut self) {
+        let task = match self.form_task() {
+            Ok(task) => task,
+            Err(err) => return self.notifier.error(err.to_string()),
+        };
+
+        let api = self.accounts.current().tasks.clone();
+        let accounts = self.accounts.selected();
 
-        let event = json!({
-            "title": self.form.title.text.to_string(),
-            "notes": self.form.notes.text.to_string(),
-            "due": format!("{}.000Z", self.form.due.text.to_string()),
-        });
+        let notifier = self.notifier.clone();
 
-        let _ = client
-            .post(BASE_API)
-            .bearer_auth(&token_info.access_token)
-            .json(&event)
-            .send();
+        self.worker.spawn(move || {
+            api.insert_task(&task).map_err(TaskError::Add)?;
+            notifier.info("Added task");
 
-        self.tasks = Self::read(token_info);
+            Tasks::read_all(&accounts).map_err(TaskError::Read)
+        });
 
         self.form.popup.active = false;
         self.form.all_clear();
     }
 
-    pub fn edit(&mut self, token_info: &TokenInfo) {
-        let client = Client::new();
-
-        let task = &mut self.tasks[self.list.index];
+    pub fn edit(&mut self) {
+        let task = self.editing.as_ref().and_then(|(account, id)| {
+            self.tasks.iter().find(|task| task.account == *account && task.item.id == *id)
+        });
+        let Some(task) = task else {
+            self.form.popup.active = false;
+            self.form.all_clear();
+            return self.notifier.error(TaskError::Missing.to_string());
+        };
 
-        task.title = self.form.title.text.to_string();
-        task.notes = self.form.notes.text.to_string();
-        task.due = self.form.due.text.to_string();
+        let patch = match self.form_patch(&task.item) {
+            Ok(patch) if patch.is_empty() => {
+                self.form.popup.active = false;
+                self.form.all_clear();
+                return self.notifier.info("No changes to save");
+            },
+            Ok(patch) => Value::Object(patch),
+            Err(err) => return self.notifier.error(err.to_string()),
+        };
 
-        let url = format!("{}/{}", BASE_API, task.id);
+        let id = task.item.id.clone();
+        let api = self.accounts.get(&task.account).tasks.clone();
+        let accounts = self.accounts.selected();
 
-        let _ = client
-            .put(url)
-            .bearer_auth(&token_info.access_token)
-            .json(&task)
-            .send();
+        let notifier = self.notifier.clone();
 
+        self.worker.spawn(move || {
+            api.patch_task(&id, &patch).map_err(TaskError::Edit)?;
+            notifier.info("Edited task");
 
-        self.tasks = Self::read(token_info);
+            Tasks::read_all(&accounts).map_err(TaskError::Read)
+        });
 
         self.form.popup.active = false;
         self.form.all_clear();
     }
 
     pub fn open(&self) {
-        Command::new(BROWSER_PATH)
-            .arg("https://calendar.google.com/calendar/u/0/r/tasks")
-            .output()
-            .expect("Failed to execute open_schedule");
+        if let Err(err) = self.browser.open("https://calendar.google.com/calendar/u/0/r/tasks") {
+            self.notifier.error(TaskError::Open(err).to_string());
+        }
     }
 
-    pub fn complete(&mut self, token_info: &TokenInfo) {
-        let client = Client::new();
+    pub fn complete(&mut self) {
+        let Some(task) = self.tasks.get_mut(self.list.index) else {
+            return;
+        };
+        task.item.status = Some("completed".to_string());
+
+        let id = task.item.id.clone();
+        let api = self.accounts.get(&task.account).tasks.clone();
+        let accounts = self.accounts.selected();
 
-        let task = &mut self.tasks[self.list.index];
-        task.status = "completed".to_string();
+        let notifier = self.notifier.clone();
 
-        let url = format!("{}/{}", BASE_API, task.id);
+        self.worker.spawn(move || {
+            api.complete_task(&id).map_err(TaskError::Complete)?;
+            notifier.info("Completed task");
 
-        let _ = client
-            .put(url)
-            .bearer_auth(&token_info.access_token)
-            .json(&task)
-            .send();
-        
-        self.tasks = Self::read(token_info);
+            Tasks::read_all(&accounts).map_err(TaskError::Read)
+        });
 
         self.form.popup.active = false;
         self.form.all_clear();
     }
 
-    pub fn delete(&mut self, token_info: &TokenInfo) {
-        let client = Client::new();
+    pub fn delete(&mut self) {
+        let Some(task) = self.tasks.get(self.list.index) else {
+            return;
+        };
 
-        let task = &self.tasks[self.list.index];
+        let id = task.item.id.clone();
+        let api = self.accounts.get(&task.account).tasks.clone();
+        let accounts = self.accounts.selected();
 
-        let url = format!("{}/{}", BASE_API, task.id);
+        let notifier = self.notifier.clone();
 
-        let _ = client
-            .delete(url)
-            .bearer_auth(&token_info.access_token)
-            .send();
+        self.worker.spawn(move || {
+            api.delete_task(&id).map_err(TaskError::Delete)?;
+            notifier.info("Deleted task");
 
-        self.tasks = Self::read(token_info);
+            Tasks::read_all(&accounts).map_err(TaskError::Read)
+        });
     }
+}
 
-    pub fn key_binding(&mut self, key: KeyEvent, token_info: &TokenInfo) {
-        match key.code {
-            KeyCode::F(1) => {
-                if !self.form.popup.active & !self.form.popup.active {
-                    self.help.popup.active = true;
-                    self.form.popup.active = false;
-                }
+impl<'a> TabApp for Tasks<'a> {
+    fn title(&self) -> &str {
+        APP_TITLE
+    }
+
+    fn pane(&mut self) -> &mut Pane {
+        &mut self.pane
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let pane = self.pane.render(frame, area);
+
+        let badge = self.accounts.is_merged();
+        let list = self.tasks.iter().map(|tasks| {
+            tasks.value(badge)
+        })
+        .collect();
+
+        self.list.render(frame, pane, list);
+    }
+
+    fn render_popup(&mut self, frame: &mut Frame) {
+        self.form.render(frame);
+        self.date_picker.render(frame);
+    }
+
+    fn on_action(&mut self, action: Action) {
+        match action {
+            Action::Add if !self.form.popup.active => {
+                self.editing = None;
+                self.form.popup.title = "Add Task".to_string();
+                self.form.mode = FormMode::New;
+                self.form.popup.active = true;
+                self.form.all_clear();
+                self.form.due_pane.preview = None;
+                self.form.active_title();
             },
-            KeyCode::F(2) => { 
-                if !self.help.popup.active & !self.form.popup.active {
-                    self.form.popup.title = "Add Task".to_string();
-                    self.form.mode = FormMode::New;
-                    self.help.popup.active = false;
-                    self.form.popup.active = true;
-                    self.form.all_clear();
-                    self.form.active_title();
-                }
+            Action::Edit if !self.form.popup.active && !self.tasks.is_empty() => {
+                let Some(task) = self.tasks.get(self.list.index) else {
+                    return;
+                };
+                self.editing = Some((task.account.clone(), task.item.id.clone()));
+
+                self.form.popup.title = "Edit Task".to_string();
+                self.form.mode = FormMode::Edit;
+                self.form.popup.active = true;
+                self.form.all_clear();
+                self.form.active_title();
+
+                let item = &task.item;
+                self.form.title.text = item.title.to_string();
+                self.form.notes.text = item.notes.clone().unwrap_or_default();
+                self.form.due.text = format_due(item.due);
+                self.form.due_pane.preview = preview_due(&self.form.due.text, self.time_zone);
             },
-            KeyCode::F(3) => { 
-                if !self.help.popup.active & !self.form.popup.active && self.tasks.len() > 0 {
-                    self.form.popup.title = "Edit Task".to_string();
-                    self.form.mode = FormMode::Edit;
-                    self.help.popup.active = false;
-                    self.form.popup.active = true;
-                    self.form.all_clear();
-                    self.form.active_title();
-
-                    let schedule = &self.tasks[self.list.index];
-                    self.form.title.text = schedule.title.to_string();
-                    self.form.notes.text = schedule.notes.to_string();
-                    self.form.due.text = schedule.due.to_string();
-                }
+            Action::Submit if self.date_picker.popup.active => self.pick_due(),
+            Action::NextField if self.date_picker.popup.active => self.date_picker.next(),
+            Action::PickDate if self.form.popup.active && self.form.due.active && !self.date_picker.popup.active => {
+                let due = parse_due(&self.form.due.text, self.time_zone)
+                    .ok()
+                    .flatten()
+                    .map_or_else(|| Utc::now().with_timezone(&self.time_zone).date_naive(), |due| due.date_naive());
+                self.date_picker.open(due, None);
             },
-            KeyCode::F(12) => {
-                if self.form.popup.active {
-                    match self.form.mode {
-                        FormMode::New => self.add(token_info),
-                        FormMode::Edit => self.edit(token_info),
-                    }
-                }
-            }
-            KeyCode::Enter => {
-                if !self.help.popup.active & !self.form.popup.active {
-                    self.open();
+            Action::Submit if self.form.popup.active => {
+                match self.form.mode {
+                    FormMode::New => self.add(),
+                    FormMode::Edit => self.edit(),
                 }
             },
-            KeyCode::Char('C') => self.complete(token_info),
-            KeyCode::Char('D') => self.delete(token_info),
-            _ => {
-                if self.form.popup.active {
-                    self.form.key_binding(key);
-                } else if !self.help.popup.active {
-                    self.list.key_binding(key);
-                }
+            Action::NextField if self.form.popup.active => {
+                self.form.next();
+            },
+            Action::Open if !self.form.popup.active => {
+                self.open();
+            },
+            Action::Complete if !self.form.popup.active && !self.tasks.is_empty() => {
+                self.complete();
+            },
+            Action::Delete if !self.form.popup.active && !self.tasks.is_empty() => {
+                self.delete();
+            },
+            Action::Refresh => self.refresh(),
+            Action::Up | Action::Left => self.list.up(),
+            Action::Down | Action::Right => self.list.down(),
+            Action::Top => self.list.top(),
+            Action::Bottom => self.list.bottom(),
+            _ => {},
+        }
+    }
+
+    fn on_input(&mut self, key: KeyEvent) {
+        if self.date_picker.popup.active {
+            match key.code {
+                KeyCode::Enter => self.pick_due(),
+                _ => self.date_picker.key_binding(key),
             }
+        } else if self.form.popup.active {
+            self.form.key_binding(key);
+            self.form.due_pane.preview = preview_due(&self.form.due.text, self.time_zone);
         }
     }
-}
\ No newline at end of file
+
+    fn help(&self) -> Vec<HelpSection> {
+        vec![
+            HelpSection::new("Select Task", Context::List, vec![
+                HelpItem::Action(Action::Open, "Open Task"),
+                HelpItem::Action(Action::Up, "Focus Move Up"),
+                HelpItem::Action(Action::Down, "Focus Move Down"),
+                HelpItem::Action(Action::Top, "Focus Move Top"),
+                HelpItem::Action(Action::Bottom, "Focus Move Bottom"),
+                HelpItem::Action(Action::Complete, "Execute Complete Task"),
+                HelpItem::Action(Action::Delete, "Execute Delete Task"),
+                HelpItem::Action(Action::Add, "Open Add Task"),
+                HelpItem::Action(Action::Edit, "Open Edit Task"),
+            ]),
+            HelpSection::new("Add Task", Context::Form, vec![
+                HelpItem::Action(Action::NextField, "Move Input Form"),
+                HelpItem::Action(Action::Submit, "Execute Add Task"),
+                HelpItem::Action(Action::Quit, "Close Add Task"),
+                HelpItem::Note("Due Date Format", "tomorrow, fri, next mon, +3d, yyyy-mm-dd"),
+                HelpItem::Action(Action::PickDate, "Open Date Picker on Due"),
+            ]),
+            HelpSection::new("Edit Task", Context::Form, vec![
+                HelpItem::Action(Action::NextField, "Move Input Form"),
+                HelpItem::Action(Action::Submit, "Execute Edit Task"),
+                HelpItem::Action(Action::Quit, "Close Edit Task"),
+                HelpItem::Note("Due Date Format", "tomorrow, fri, next mon, +3d, yyyy-mm-dd"),
+                HelpItem::Action(Action::PickDate, "Open Date Picker on Due"),
+            ]),
+            HelpSection::new("Pick Date", Context::Form, vec![
+                HelpItem::Note("Arrows", "Move Day/Week"),
+                HelpItem::Note("PageUp/PageDown", "Move Month"),
+                HelpItem::Action(Action::Submit, "Apply Date (also Enter)"),
+                HelpItem::Action(Action::Quit, "Close Date Picker"),
+            ]),
+        ]
+    }
+
+    fn has_open_popup(&self) -> bool {
+        self.form.popup.active
+    }
+
+    fn close_popup(&mut self) {
+        if self.date_picker.popup.active {
+            self.date_picker.popup.active = false;
+            return;
+        }
+
+        self.form.popup.active = false;
+    }
+
+    fn refresh(&mut self) {
+        self.interval.reset();
+        if self.worker.is_busy() {
+            self.stale = true;
+            return;
+        }
+
+        self.stale = false;
+
+        let accounts = self.accounts.selected();
+
+        self.worker.spawn(move || Tasks::read_all(&accounts).map_err(TaskError::Read));
+    }
+
+    fn set_accounts(&mut self, accounts: &Accounts) {
+        self.accounts = accounts.clone();
+        self.refresh();
+    }
+
+    fn tick(&mut self) {
+        while let Some(result) = self.worker.try_recv() {
+            match result {
+                Ok(tasks) => {
+                    self.tasks = tasks;
+                    self.list.clamp(self.tasks.len());
+                    self.pane.synced = Some(Local::now());
+                },
+                Err(err) => self.notifier.error(err.to_string()),
+            }
+        }
+
+        self.pane.loading = self.worker.is_busy();
+
+        if (self.stale || self.interval.is_due()) && !self.pane.loading {
+            self.refresh();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::app::notification::Notification;
+
+    fn task(id: &str, title: &str) -> Task {
+        Task {
+            item: tasks::Task { id: id.to_string(), title: title.to_string(), ..tasks::Task::default() },
+            account: "work".to_string(),
+        }
+    }
+
+    fn messages(receiver: &std::sync::mpsc::Receiver<Notification>) -> Vec<String> {
+        receiver.try_iter().map(|notification| notification.message).collect()
+    }
+
+    #[test]
+    fn parses_due_dates_in_the_configured_time_zone() {
+        for time_zone in [Tz::Pacific__Kiritimati, Tz::Pacific__Pago_Pago] {
+            let today = Utc::now().with_timezone(&time_zone).date_naive();
+            let due = parse_due("today", time_zone).unwrap().unwrap();
+
+            assert_eq!(due.date_naive(), today);
+        }
+        assert!(parse_due(" ", Tz::UTC).unwrap().is_none());
+        assert!(matches!(parse_due("someday", Tz::UTC), Err(TaskError::InvalidDue(_))));
+    }
+
+    #[test]
+    fn reports_a_task_removed_while_editing() {
+        let (notifier, receiver) = Notifier::new();
+        let mut app = Tasks::new(&Config::default(), &Accounts::new(Vec::new(), false), &notifier);
+        app.tasks = vec![task("1", "Write report")];
+
+        app.on_action(Action::Edit);
+        assert_eq!(app.form.title.text, "Write report");
+
+        app.tasks.clear();
+        app.list.clamp(app.tasks.len());
+        app.form.title.set("Write summary");
+        app.on_action(Action::Submit);
+
+        assert!(!app.form.popup.active);
+        assert!(!app.worker.is_busy());
+        assert_eq!(messages(&receiver), ["Task no longer exists"]);
+    }
+
+    #[test]
+    fn edits_the_task_opened_in_the_form() {
+        let (notifier, receiver) = Notifier::new();
+        let mut app = Tasks::new(&Config::default(), &Accounts::new(Vec::new(), false), &notifier);
+        app.tasks = vec![task("1", "Write report"), task("2", "Book flight")];
+        app.list.index = 1;
+
+        app.on_action(Action::Edit);
+        app.tasks.remove(0);
+        app.list.clamp(app.tasks.len());
+        app.on_action(Action::Submit);
+
+        assert_eq!(messages(&receiver), ["No changes to save"]);
+    }
+}
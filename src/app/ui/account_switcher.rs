@@ -0,0 +1,92 @@
+use ratatui::{
+    layout::{Constraint, Layout, Margin},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::app::google::account::Accounts;
+use crate::app::keymap::{Action, Context, Keymap};
+use crate::app::ui::{
+    popup::Popup,
+    select_list::SelectList,
+};
+
+#[derive(Debug)]
+pub struct AccountSwitcher<'a> {
+    pub popup: Popup,
+    list: SelectList<'a>,
+}
+
+impl<'a> AccountSwitcher<'a> {
+    pub fn new() -> Self {
+        Self {
+            popup: Popup::new("Accounts"),
+            list: SelectList::new(),
+        }
+    }
+
+    pub fn open(&mut self, accounts: &Accounts) {
+        self.popup.active = true;
+        self.list.index = if accounts.is_merged() {
+            accounts.list.len()
+        } else {
+            accounts.current
+        };
+    }
+
+    pub fn on_action(&mut self, action: Action, accounts: &mut Accounts) -> bool {
+        match action {
+            Action::Up | Action::Left => self.list.up(),
+            Action::Down | Action::Right => self.list.down(),
+            Action::Top => self.list.top(),
+            Action::Bottom => self.list.bottom(),
+            Action::Open | Action::Submit => {
+                if self.list.index < accounts.list.len() {
+                    accounts.current = self.list.index;
+                    accounts.merged = false;
+                } else {
+                    accounts.merged = true;
+                }
+                self.popup.active = false;
+
+                return true;
+            },
+            _ => {},
+        }
+
+        false
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, keymap: &Keymap, accounts: &Accounts) {
+        if self.popup.active {
+            let area = self.popup.render(frame, [40, 40]);
+
+            let layout = Layout::vertical([
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ]);
+            let [list_area, hint_area] = layout.areas(area.inner(Margin::new(2, 1)));
+
+            let mut list: Vec<String> = accounts.list
+                .iter()
+                .enumerate()
+                .map(|(i, account)| {
+                    let mark = if !accounts.is_merged() && i == accounts.current { "*" } else { " " };
+                    format!("{} {}", mark, account.name)
+                })
+                .collect();
+            if accounts.list.len() > 1 {
+                let mark = if accounts.is_merged() { "*" } else { " " };
+                list.push(format!("{} All accounts (merged)", mark));
+            }
+
+            self.list.render(frame, list_area, list);
+
+            let hint = format!("Switch: {}, Close: {}",
+                keymap.describe(Context::List, Action::Open),
+                keymap.describe(Context::List, Action::Quit),
+            );
+            frame.render_widget(Paragraph::new(hint), hint_area);
+        }
+    }
+}
@@ -1,13 +1,17 @@
 use ratatui::{
-    crossterm::event::{KeyCode, KeyEvent}, 
-    layout::{Constraint, Layout, Rect}, 
-    widgets::Paragraph, 
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
     Frame,
 };
 
+#[derive(Debug)]
 pub struct CheckBox {
     check: bool,
     label: String,
+    color: Option<Color>,
 }
 
 impl CheckBox {
@@ -15,32 +19,56 @@ impl CheckBox {
         Self {
             check: false,
             label: label.to_string(),
+            color: None,
         }
     }
 
-    pub fn render(&self, frame: &mut Frame, area: Rect) {
+    pub fn with_color(mut self, color: Option<Color>) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, focused: bool) {
         let layout = Layout::horizontal([
             Constraint::Length(3),
             Constraint::Min(0),
         ]);
         let [check_area, label_area] = layout.areas(area);
 
-        let label = Paragraph::new(self.label.to_string());
-        frame.render_widget(label, label_area);
+        let style = if focused {
+            Style::new().fg(Color::Green)
+        } else {
+            Style::new()
+        };
+        let mut label = Vec::new();
+        if let Some(color) = self.color {
+            label.push(Span::styled("■ ", Style::new().fg(color)));
+        }
+        label.push(Span::styled(self.label.to_string(), style));
+        frame.render_widget(Paragraph::new(Line::from(label)), label_area);
 
         let check = if self.check {
             Paragraph::new("☑")
         } else {
             Paragraph::new("☐")
         };
-        frame.render_widget(check, check_area);
+        frame.render_widget(check.style(style), check_area);
     }
 
     pub fn checked(&mut self) {
         self.check = true;
     }
+
+    pub fn toggle(&mut self) {
+        self.check = !self.check;
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.check
+    }
 }
 
+#[derive(Debug)]
 pub struct CheckBoxes {
     pub index: usize,
     pub checkboxes: Vec<CheckBox>,
@@ -54,33 +82,51 @@ impl CheckBoxes {
         }
     }
 
-    pub fn render(&mut self, frame: &mut Frame, area: Rect, list: Vec<&str>, vertical: bool) {
-        let constraints: Vec<Constraint> = list
-            .iter().map(|_| Constraint::Length(100 / list.len() as u16)).collect();
-
-        let layout = if vertical {
-            Layout::vertical(constraints)
+    fn layout(labels: &[&str], vertical: bool) -> Layout {
+        if vertical {
+            Layout::vertical(labels.iter().map(|_| Constraint::Length(1)))
         } else {
-            Layout::horizontal(constraints)
-        };
+            Layout::horizontal(labels.iter().map(|label| Constraint::Length(label.chars().count() as u16 + 5)))
+        }
+    }
 
-        self.checkboxes = list 
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, list: Vec<&str>, vertical: bool) {
+        self.checkboxes = list
             .iter()
             .map(|label| CheckBox::new(label))
             .collect();
 
-        layout.split(area).iter().enumerate().for_each(|(i, checkbox_area)| {
+        Self::layout(&list, vertical).split(area).iter().enumerate().for_each(|(i, checkbox_area)| {
             let checkbox = &mut self.checkboxes[i];
 
             if self.index == i {
                 checkbox.checked();
             }
-            checkbox.render(frame, *checkbox_area);
+            checkbox.render(frame, *checkbox_area, false);
+        });
+    }
+
+    pub fn set(&mut self, checkboxes: Vec<CheckBox>) {
+        self.checkboxes = checkboxes;
+        self.index = self.index.min(self.checkboxes.len().saturating_sub(1));
+    }
+
+    pub fn render_checked(&self, frame: &mut Frame, area: Rect, vertical: bool, focused: bool) {
+        let labels: Vec<&str> = self.checkboxes.iter().map(|checkbox| checkbox.label.as_str()).collect();
+
+        Self::layout(&labels, vertical).split(area).iter().enumerate().for_each(|(i, checkbox_area)| {
+            self.checkboxes[i].render(frame, *checkbox_area, focused && self.index == i);
         });
     }
 
+    pub fn toggle(&mut self) {
+        if let Some(checkbox) = self.checkboxes.get_mut(self.index) {
+            checkbox.toggle();
+        }
+    }
+
     pub fn up(&mut self) {
-        self.index = self.index.saturating_sub(1).clamp(0, self.checkboxes.len() - 1);
+        self.index = self.index.saturating_sub(1).min(self.checkboxes.len().saturating_sub(1));
     }
 
     pub fn right(&mut self) {
@@ -88,7 +134,7 @@ impl CheckBoxes {
     }
 
     pub fn down(&mut self) {
-        self.index = self.index.saturating_add(1).clamp(0, self.checkboxes.len() - 1);
+        self.index = self.index.saturating_add(1).min(self.checkboxes.len().saturating_sub(1));
     }
 
     pub fn left(&mut self) {
@@ -108,4 +154,4 @@ impl CheckBoxes {
             _ => {},
         }
     }
-}
\ No newline at end of file
+}
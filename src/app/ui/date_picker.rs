@@ -0,0 +1,264 @@
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveTime, Timelike};
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Margin, Rect},
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+    Frame,
+};
+
+use crate::app::ui::{month_view, popup::Popup};
+
+const WIDTH: u16 = 20;
+const HEIGHT: u16 = 10;
+const WEEKDAYS: &str = "Mo Tu We Th Fr Sa Su";
+const MINUTE_STEP: i64 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Focus {
+    Date,
+    Hour,
+    Minute,
+}
+
+#[derive(Debug)]
+pub struct DatePicker {
+    pub popup: Popup,
+    pub date: NaiveDate,
+    pub time: Option<NaiveTime>,
+    focus: Focus,
+}
+
+impl DatePicker {
+    pub fn new() -> Self {
+        Self {
+            popup: Popup::new("Pick Date"),
+            date: NaiveDate::default(),
+            time: None,
+            focus: Focus::Date,
+        }
+    }
+
+    pub fn open(&mut self, date: NaiveDate, time: Option<NaiveTime>) {
+        self.popup.active = true;
+        self.date = date;
+        self.time = time;
+        self.focus = Focus::Date;
+    }
+
+    pub fn next(&mut self) {
+        self.focus = match self.focus {
+            Focus::Date if self.time.is_some() => Focus::Hour,
+            Focus::Hour => Focus::Minute,
+            _ => Focus::Date,
+        };
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        if self.popup.active {
+            let area = self.popup.render_fixed(frame, [WIDTH + 4, HEIGHT + 2]);
+
+            frame.render_widget(self, area.inner(Margin::new(2, 1)));
+        }
+    }
+
+    fn move_days(&mut self, days: i64) {
+        self.date += Duration::days(days);
+    }
+
+    fn move_months(&mut self, months: i32) {
+        let date = if months < 0 {
+            self.date.checked_sub_months(Months::new(months.unsigned_abs()))
+        } else {
+            self.date.checked_add_months(Months::new(months.unsigned_abs()))
+        };
+        self.date = date.unwrap_or(self.date);
+    }
+
+    fn spin(&mut self, steps: i64) {
+        let Some(time) = self.time else {
+            return;
+        };
+
+        let minutes = match self.focus {
+            Focus::Hour => steps * 60,
+            _ => steps * MINUTE_STEP,
+        };
+        let time = time.overflowing_add_signed(Duration::minutes(minutes)).0;
+        self.time = time.with_second(0);
+    }
+
+    pub fn key_binding(&mut self, key: KeyEvent) {
+        match (self.focus, key.code) {
+            (_, KeyCode::PageUp) => self.move_months(-1),
+            (_, KeyCode::PageDown) => self.move_months(1),
+            (Focus::Date, KeyCode::Left) => self.move_days(-1),
+            (Focus::Date, KeyCode::Right) => self.move_days(1),
+            (Focus::Date, KeyCode::Up) => self.move_days(-7),
+            (Focus::Date, KeyCode::Down) => self.move_days(7),
+            (_, KeyCode::Up) => self.spin(1),
+            (_, KeyCode::Down) => self.spin(-1),
+            (Focus::Minute, KeyCode::Left) => self.focus = Focus::Hour,
+            (Focus::Hour, KeyCode::Right) => self.focus = Focus::Minute,
+            _ => {},
+        }
+    }
+
+    fn style(&self, focus: Focus) -> Style {
+        if self.focus == focus {
+            Style::new().fg(Color::Black).bg(Color::Green)
+        } else {
+            Style::new().fg(Color::Green).add_modifier(Modifier::BOLD)
+        }
+    }
+}
+
+impl Widget for &DatePicker {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width < WIDTH || area.height < HEIGHT {
+            return;
+        }
+
+        let title = format!("{:^width$}", self.date.format("%B %Y").to_string(), width = usize::from(WIDTH));
+        buf.set_string(area.x, area.y, title, Style::new().add_modifier(Modifier::BOLD));
+        buf.set_string(area.x, area.y + 1, WEEKDAYS, Style::new().fg(Color::DarkGray));
+
+        let start = month_view::month_start(self.date);
+        for week in 0..6 {
+            for day in 0..7 {
+                let date = start + Duration::days(i64::from(week * 7 + day));
+                if date.month() != self.date.month() {
+                    continue;
+                }
+
+                let style = if date == self.date { self.style(Focus::Date) } else { Style::new() };
+                buf.set_string(area.x + day * 3, area.y + 2 + week, format!("{:>2}", date.day()), style);
+            }
+        }
+
+        if let Some(time) = self.time {
+            let y = area.y + HEIGHT - 1;
+            buf.set_string(area.x, y, "Time", Style::new().fg(Color::DarkGray));
+            buf.set_string(area.x + 6, y, format!("{:02}", time.hour()), self.style(Focus::Hour));
+            buf.set_string(area.x + 8, y, ":", Style::new());
+            buf.set_string(area.x + 9, y, format!("{:02}", time.minute()), self.style(Focus::Minute));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, crossterm::event::KeyModifiers, Terminal};
+
+    use super::*;
+
+    fn picker(date: &str, time: Option<&str>) -> DatePicker {
+        let mut picker = DatePicker::new();
+        picker.open(
+            NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            time.map(|time| NaiveTime::parse_from_str(time, "%H:%M").unwrap()),
+        );
+        picker
+    }
+
+    fn press(picker: &mut DatePicker, code: KeyCode) {
+        picker.key_binding(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    fn draw(picker: &DatePicker) -> Terminal<TestBackend> {
+        let mut terminal = Terminal::new(TestBackend::new(WIDTH + 4, HEIGHT + 2)).unwrap();
+        terminal.draw(|frame| picker.render(frame)).unwrap();
+        terminal
+    }
+
+    fn snapshot(picker: &DatePicker) -> Vec<String> {
+        let terminal = draw(picker);
+        let buffer = terminal.backend().buffer();
+
+        (0..buffer.area.height)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn renders_month_grid_with_time() {
+        let picker = picker("2024-12-04", Some("09:30"));
+
+        assert_eq!(snapshot(&picker), [
+            "┌  Pick Date  ─────────┐",
+            "│    December 2024     │",
+            "│ Mo Tu We Th Fr Sa Su │",
+            "│                    1 │",
+            "│  2  3  4  5  6  7  8 │",
+            "│  9 10 11 12 13 14 15 │",
+            "│ 16 17 18 19 20 21 22 │",
+            "│ 23 24 25 26 27 28 29 │",
+            "│ 30 31                │",
+            "│                      │",
+            "│ Time  09:30          │",
+            "└──────────────────────┘",
+        ]);
+    }
+
+    #[test]
+    fn renders_date_only_without_time() {
+        let picker = picker("2025-02-14", None);
+
+        assert_eq!(snapshot(&picker), [
+            "┌  Pick Date  ─────────┐",
+            "│    February 2025     │",
+            "│ Mo Tu We Th Fr Sa Su │",
+            "│                 1  2 │",
+            "│  3  4  5  6  7  8  9 │",
+            "│ 10 11 12 13 14 15 16 │",
+            "│ 17 18 19 20 21 22 23 │",
+            "│ 24 25 26 27 28       │",
+            "│                      │",
+            "│                      │",
+            "│                      │",
+            "└──────────────────────┘",
+        ]);
+    }
+
+    #[test]
+    fn highlights_the_focused_part() {
+        let mut picker = picker("2024-12-04", Some("09:30"));
+
+        let terminal = draw(&picker);
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(9, 4)].symbol(), "4");
+        assert_eq!(buffer[(9, 4)].bg, Color::Green);
+        assert_eq!(buffer[(8, 10)].bg, Color::Reset);
+
+        picker.next();
+        let terminal = draw(&picker);
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(9, 4)].bg, Color::Reset);
+        assert_eq!(buffer[(8, 10)].symbol(), "0");
+        assert_eq!(buffer[(8, 10)].bg, Color::Green);
+    }
+
+    #[test]
+    fn navigates_days_months_and_time() {
+        let mut picker = picker("2024-01-31", Some("23:55"));
+
+        press(&mut picker, KeyCode::Right);
+        press(&mut picker, KeyCode::Down);
+        assert_eq!(picker.date, NaiveDate::from_ymd_opt(2024, 2, 8).unwrap());
+
+        press(&mut picker, KeyCode::PageUp);
+        press(&mut picker, KeyCode::PageUp);
+        assert_eq!(picker.date, NaiveDate::from_ymd_opt(2023, 12, 8).unwrap());
+
+        picker.next();
+        press(&mut picker, KeyCode::Up);
+        assert_eq!(picker.time, NaiveTime::from_hms_opt(0, 55, 0));
+
+        press(&mut picker, KeyCode::Right);
+        press(&mut picker, KeyCode::Up);
+        press(&mut picker, KeyCode::Up);
+        assert_eq!(picker.time, NaiveTime::from_hms_opt(1, 5, 0));
+        assert_eq!(picker.date, NaiveDate::from_ymd_opt(2023, 12, 8).unwrap());
+    }
+}
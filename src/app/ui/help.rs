@@ -1,24 +1,187 @@
-use ratatui::Frame;
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Constraint, Layout, Margin},
+    text::Line,
+    widgets::Paragraph,
+    Frame,
+};
 
-use crate::app::ui::popup::Popup;
+use crate::app::keymap::{Action, Context, Keymap};
+use crate::app::ui::{
+    input::Input,
+    popup::Popup,
+};
+
+#[derive(Debug)]
+pub enum HelpItem {
+    Action(Action, &'static str),
+    Note(&'static str, &'static str),
+}
+
+#[derive(Debug)]
+pub struct HelpSection {
+    pub title: &'static str,
+    pub context: Context,
+    pub items: Vec<HelpItem>,
+}
+
+impl HelpSection {
+    pub fn new(title: &'static str, context: Context, items: Vec<HelpItem>) -> Self {
+        Self { title, context, items }
+    }
+
+    fn lines(&self, keymap: &Keymap, filter: &str) -> Vec<String> {
+        let entries: Vec<(&str, String)> = self.items.iter().map(|item| match item {
+            HelpItem::Action(action, label) => (*label, keymap.describe(self.context, *action)),
+            HelpItem::Note(label, value) => (*label, value.to_string()),
+        })
+        .filter(|(_, value)| !value.is_empty())
+        .collect();
+
+        let width = entries.iter().map(|(label, _)| label.chars().count()).max().unwrap_or(0);
+
+        let filter = filter.to_lowercase();
+        let lines: Vec<String> = entries
+            .iter()
+            .map(|(label, value)| format!("{:<width$}: {}", label, value, width = width))
+            .filter(|line| line.to_lowercase().contains(&filter))
+            .collect();
+
+        if lines.is_empty() {
+            return lines;
+        }
+
+        let mut section = vec![format!("[ {} ]", self.title)];
+        section.extend(lines);
+        section
+    }
+}
 
 #[derive(Debug)]
 pub struct Help {
     pub popup: Popup,
+    scroll: usize,
+    filter: Input,
+    searching: bool,
 }
 
 impl Help {
     pub fn new(title: &str) -> Self {
         Self {
-            popup: Popup::new(title)
+            popup: Popup::new(title),
+            scroll: 0,
+            filter: Input::new(),
+            searching: false,
+        }
+    }
+
+    pub fn open(&mut self, title: String) {
+        self.popup.title = title;
+        self.popup.active = true;
+        self.scroll = 0;
+        self.searching = false;
+        self.filter.clear();
+        self.filter.active = false;
+    }
+
+    pub fn back(&mut self) {
+        if self.searching {
+            self.searching = false;
+            self.filter.clear();
+            self.filter.active = false;
+        } else {
+            self.popup.active = false;
+        }
+    }
+
+    pub fn context(&self) -> Context {
+        if self.searching {
+            Context::Form
+        } else {
+            Context::List
+        }
+    }
+
+    pub fn on_action(&mut self, action: Action) {
+        match action {
+            Action::Up | Action::Left => self.scroll = self.scroll.saturating_sub(1),
+            Action::Down | Action::Right => self.scroll = self.scroll.saturating_add(1),
+            Action::Top => self.scroll = 0,
+            Action::Bottom => self.scroll = usize::MAX,
+            Action::Search => {
+                self.searching = true;
+                self.scroll = 0;
+                self.filter.clear();
+                self.filter.active = true;
+            },
+            _ => {},
         }
     }
 
-    pub fn render(&self, frame: &mut Frame, text: Vec<&str>) {
+    pub fn on_input(&mut self, key: KeyEvent) {
+        if self.searching {
+            match key.code {
+                KeyCode::Enter => {
+                    self.searching = false;
+                    self.filter.active = false;
+                },
+                _ => {
+                    self.filter.key_binding(key);
+                    self.scroll = 0;
+                },
+            }
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, keymap: &Keymap, sections: Vec<HelpSection>) {
         if self.popup.active {
             let area = self.popup.render(frame, [80, 80]);
 
-            self.popup.text(frame, area, text, false);
+            let layout = Layout::vertical([
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ]);
+            let [text_area, search_area] = layout.areas(area.inner(Margin::new(2, 1)));
+
+            let mut lines: Vec<String> = Vec::new();
+            for section in sections {
+                let section_lines = section.lines(keymap, &self.filter.text);
+                if !section_lines.is_empty() {
+                    if !lines.is_empty() {
+                        lines.push(String::new());
+                    }
+                    lines.extend(section_lines);
+                }
+            }
+            if lines.is_empty() {
+                lines.push(format!("No help matches \"{}\"", self.filter.text));
+            }
+
+            let max_scroll = lines.len().saturating_sub(text_area.height as usize);
+            self.scroll = self.scroll.min(max_scroll);
+
+            let text: Vec<Line> = lines.into_iter().map(Line::from).collect();
+            let text = Paragraph::new(text).scroll((self.scroll as u16, 0));
+            frame.render_widget(text, text_area);
+
+            if self.searching || !self.filter.text.is_empty() {
+                let layout = Layout::horizontal([
+                    Constraint::Length(2),
+                    Constraint::Min(0),
+                ]);
+                let [prompt_area, input_area] = layout.areas(search_area);
+
+                frame.render_widget(Paragraph::new("/"), prompt_area);
+                self.filter.render(frame, input_area);
+            } else {
+                let search = keymap.describe(Context::List, Action::Search);
+                let hint = format!("Search: {}, Scroll: {} / {}",
+                    search,
+                    keymap.describe(Context::List, Action::Up),
+                    keymap.describe(Context::List, Action::Down),
+                );
+                frame.render_widget(Paragraph::new(hint), search_area);
+            }
         }
     }
-}
\ No newline at end of file
+}
@@ -55,6 +55,11 @@ impl Input {
             .clamp(0, self.text.chars().count());
     }
 
+    pub fn set(&mut self, text: &str) {
+        self.text = text.to_string();
+        self.index = self.text.chars().count();
+    }
+
     pub fn clear(&mut self) {
         self.index = 0;
         self.text.clear();
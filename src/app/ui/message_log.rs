@@ -0,0 +1,84 @@
+use ratatui::{
+    layout::{Constraint, Layout, Margin},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::app::keymap::{Action, Context, Keymap};
+use crate::app::notification::{Level, Notification};
+use crate::app::ui::popup::Popup;
+
+#[derive(Debug)]
+pub struct MessageLog {
+    pub popup: Popup,
+    scroll: usize,
+}
+
+impl MessageLog {
+    pub fn new() -> Self {
+        Self {
+            popup: Popup::new("Messages"),
+            scroll: 0,
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.popup.active = true;
+        self.scroll = usize::MAX;
+    }
+
+    pub fn on_action(&mut self, action: Action) {
+        match action {
+            Action::Up | Action::Left => self.scroll = self.scroll.saturating_sub(1),
+            Action::Down | Action::Right => self.scroll = self.scroll.saturating_add(1),
+            Action::Top => self.scroll = 0,
+            Action::Bottom => self.scroll = usize::MAX,
+            _ => {},
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, keymap: &Keymap, messages: &[Notification]) {
+        if self.popup.active {
+            let area = self.popup.render(frame, [80, 80]);
+
+            let layout = Layout::vertical([
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ]);
+            let [text_area, hint_area] = layout.areas(area.inner(Margin::new(2, 1)));
+
+            let lines: Vec<Line> = if messages.is_empty() {
+                vec![Line::from("No messages")]
+            } else {
+                messages.iter().map(Self::line).collect()
+            };
+
+            let max_scroll = lines.len().saturating_sub(text_area.height as usize);
+            self.scroll = self.scroll.min(max_scroll);
+
+            let text = Paragraph::new(lines).scroll((self.scroll as u16, 0));
+            frame.render_widget(text, text_area);
+
+            let hint = format!("Close: {}, Scroll: {} / {}",
+                keymap.describe(Context::List, Action::Quit),
+                keymap.describe(Context::List, Action::Up),
+                keymap.describe(Context::List, Action::Down),
+            );
+            frame.render_widget(Paragraph::new(hint), hint_area);
+        }
+    }
+
+    fn line(notification: &Notification) -> Line<'_> {
+        let style = match notification.level {
+            Level::Info => Style::default(),
+            Level::Error => Style::default().fg(Color::Red),
+        };
+
+        Line::from(vec![
+            Span::raw(format!("{} ", notification.time.format("%H:%M:%S"))),
+            Span::styled(notification.message.as_str(), style),
+        ])
+    }
+}
@@ -1,6 +1,11 @@
+pub mod account_switcher;
 pub mod checkbox;
+pub mod date_picker;
 pub mod help;
 pub mod input;
+pub mod message_log;
+pub mod month_view;
 pub mod pane;
 pub mod popup;
-pub mod select_list;
\ No newline at end of file
+pub mod select_list;
+pub mod week_view;
\ No newline at end of file
@@ -0,0 +1,86 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+use crate::app::ui::week_view::{week_start, DayEvent};
+
+const WEEKS: u16 = 6;
+
+pub fn month_start(date: NaiveDate) -> NaiveDate {
+    week_start(date.with_day(1).unwrap_or(date))
+}
+
+pub fn month_end(date: NaiveDate) -> NaiveDate {
+    month_start(date) + Duration::days(i64::from(WEEKS) * 7)
+}
+
+#[derive(Debug)]
+pub struct MonthView<'a> {
+    pub cursor: NaiveDate,
+    pub events: &'a [DayEvent],
+}
+
+impl Widget for MonthView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let cell_width = area.width / 7;
+        let cell_height = area.height.saturating_sub(1) / WEEKS;
+        if cell_width < 3 || cell_height == 0 {
+            return;
+        }
+
+        let start = month_start(self.cursor);
+
+        for day in 0..7 {
+            let name = (start + Duration::days(i64::from(day))).format("%a").to_string();
+            buf.set_stringn(area.x + day * cell_width, area.y, name, usize::from(cell_width - 1), Style::new().add_modifier(Modifier::BOLD));
+        }
+
+        for week in 0..WEEKS {
+            for day in 0..7 {
+                let date = start + Duration::days(i64::from(week * 7 + day));
+                let x = area.x + day * cell_width;
+                let y = area.y + 1 + week * cell_height;
+                let width = usize::from(cell_width - 1);
+
+                let style = if date == self.cursor {
+                    Style::new().fg(Color::Black).bg(Color::Green)
+                } else if date.month() != self.cursor.month() {
+                    Style::new().fg(Color::DarkGray)
+                } else {
+                    Style::new().add_modifier(Modifier::BOLD)
+                };
+                buf.set_stringn(x, y, format!("{:<width$}", date.day(), width = width), width, style);
+
+                let events: Vec<&DayEvent> = self.events.iter().filter(|event| event.is_on(date)).collect();
+                let lines = usize::from(cell_height - 1);
+                if events.is_empty() || lines == 0 {
+                    if !events.is_empty() {
+                        buf.set_stringn(x + 3, y, format!("({})", events.len()), width.saturating_sub(3), style);
+                    }
+                    continue;
+                }
+
+                for (line, event) in events.iter().take(lines).enumerate() {
+                    let text = if line + 1 == lines && events.len() > lines {
+                        format!("+{} more", events.len() - line)
+                    } else {
+                        event.title.clone()
+                    };
+                    let style = if event.selected {
+                        Style::new().fg(Color::Green)
+                    } else if let Some(color) = event.color {
+                        Style::new().fg(color)
+                    } else {
+                        Style::new()
+                    };
+                    buf.set_stringn(x, y + 1 + line as u16, text, width, style);
+                }
+            }
+        }
+    }
+}
+
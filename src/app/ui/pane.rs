@@ -1,35 +1,86 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Local};
 use ratatui::{
-    layout::Rect, 
-    style::{Color, Style}, 
-    widgets::Block, 
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::Block,
     Frame,
 };
 
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Preview {
+    Valid(String),
+    Invalid(String),
+}
+
 #[derive(Debug)]
 pub struct Pane {
     title: String,
+    pub preview: Option<Preview>,
     pub active: bool,
+    pub loading: bool,
+    pub synced: Option<DateTime<Local>>,
 }
 
 impl Pane {
     pub fn new(title: &str) -> Self {
         Self {
             title: title.to_string(),
+            preview: None,
             active: false,
+            loading: false,
+            synced: None,
         }
     }
 
+    pub fn set_title(&mut self, title: &str) {
+        self.title = title.to_string();
+    }
+
     pub fn render(&self, frame: &mut Frame, area: Rect) -> Rect {
-        let outer = if self.active {
-            Block::bordered()
-                .border_style(Style::new().fg(Color::Green))
-                .title(format!("  {}  ", self.title))
+        let title = if self.loading {
+            format!("  {} {}  ", self.title, Self::spinner())
         } else {
-            Block::bordered().title(format!("  {}  ", self.title))
+            format!("  {}  ", self.title)
+        };
+
+        let mut outer = match &self.preview {
+            Some(Preview::Invalid(_)) => Block::bordered()
+                .border_style(Style::new().fg(Color::Red))
+                .title(title),
+            _ if self.active => Block::bordered()
+                .border_style(Style::new().fg(Color::Green))
+                .title(title),
+            _ => Block::bordered().title(title),
         };
+        match &self.preview {
+            Some(Preview::Valid(text)) => {
+                outer = outer.title_bottom(Line::styled(format!(" → {} ", text), Style::new().fg(Color::Cyan)));
+            },
+            Some(Preview::Invalid(text)) => {
+                outer = outer.title_bottom(Line::styled(format!(" {} ", text), Style::new().fg(Color::Red)));
+            },
+            None => {},
+        }
+        if let Some(synced) = self.synced {
+            outer = outer.title(Line::from(format!(" synced {} ", synced.format("%H:%M:%S"))).right_aligned());
+        }
         let inner = outer.inner(area);
         frame.render_widget(outer, area);
 
         inner
     }
-}
\ No newline at end of file
+
+    fn spinner() -> &'static str {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis())
+            .unwrap_or(0);
+
+        SPINNER[(millis / 100) as usize % SPINNER.len()]
+    }
+}
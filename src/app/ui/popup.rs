@@ -1,8 +1,7 @@
 use ratatui::{
-    layout::{Constraint, Flex, Layout, Margin, Rect}, 
+    layout::{Constraint, Flex, Layout, Rect}, 
     style::{Color, Style}, 
-    text::{Line, Span}, 
-    widgets::{Block, Clear, Paragraph}, 
+    widgets::{Block, Clear}, 
     Frame,
 };
 
@@ -21,16 +20,18 @@ impl Popup {
     }
 
     pub fn render(&self, frame: &mut Frame, percent: [u16; 2]) -> Rect {
-        let horizontal = Layout::horizontal([
-            Constraint::Percentage(percent[0])
-        ])
-        .flex(Flex::Center);
+        self.render_in(frame, [Constraint::Percentage(percent[0]), Constraint::Percentage(percent[1])])
+    }
+
+    pub fn render_fixed(&self, frame: &mut Frame, size: [u16; 2]) -> Rect {
+        self.render_in(frame, [Constraint::Length(size[0]), Constraint::Length(size[1])])
+    }
+
+    fn render_in(&self, frame: &mut Frame, [width, height]: [Constraint; 2]) -> Rect {
+        let horizontal = Layout::horizontal([width]).flex(Flex::Center);
         let [area] = horizontal.areas(frame.area());
 
-        let vertical = Layout::vertical([
-            Constraint::Percentage(percent[1])
-        ])
-        .flex(Flex::Center);
+        let vertical = Layout::vertical([height]).flex(Flex::Center);
         let [area] = vertical.areas(area);
 
         frame.render_widget(Clear, area);
@@ -45,19 +46,4 @@ impl Popup {
 
         area
     }
-
-    pub fn text(&self, frame: &mut Frame, area: Rect, text: Vec<&str>, center: bool) {
-        let text_box: Vec<Line> = text.iter().map(|&line| {
-            Line::from(Span::raw(line))
-        })
-        .collect();
-        let text_box = if center {
-            Paragraph::new(text_box).centered()
-        } else {
-            Paragraph::new(text_box)
-        };
-
-        let text_area = area.inner(Margin::new(2, 1));
-        frame.render_widget(text_box, text_area);
-    }
 }
@@ -1,15 +1,18 @@
 use ratatui::{
-    crossterm::event::{KeyCode, KeyEvent}, 
     layout::Rect, 
-    style::{Color, Style},
-    widgets::{List, ListItem},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{List, ListItem, ListState},
     Frame,
 };
 
+pub type ColoredItem = (Option<Color>, String);
+
 #[derive(Debug)]
 pub struct SelectList<'a> {
     pub index: usize,
     pub list: Vec<ListItem<'a>>,
+    state: ListState,
 }
 
 impl<'a> SelectList<'a> {
@@ -17,6 +20,7 @@ impl<'a> SelectList<'a> {
         Self {
             index: 0,
             list: Vec::new(),
+            state: ListState::default(),
         }
     }
 
@@ -35,35 +39,114 @@ impl<'a> SelectList<'a> {
         frame.render_widget(list, area);
     }
 
+    pub fn render_grouped(&mut self, frame: &mut Frame, area: Rect, groups: Vec<(String, Vec<ColoredItem>)>) {
+        let mut items = Vec::new();
+        let mut selected_row = None;
+        self.list.clear();
+
+        for (header, values) in groups {
+            items.push(ListItem::new(header).style(Style::new().add_modifier(Modifier::BOLD)));
+
+            for (color, value) in values {
+                let marker = Span::styled("■ ", Style::new().fg(color.unwrap_or(Color::Reset)));
+                let selected = self.list.len() == self.index;
+                if selected {
+                    selected_row = Some(items.len());
+                }
+                let mut prefix = vec![marker];
+                if selected {
+                    prefix.insert(0, Span::raw("> "));
+                }
+                let indent = " ".repeat(prefix.iter().map(Span::width).sum());
+
+                let mut lines = value.split('\n');
+                let mut first = prefix;
+                first.push(Span::raw(lines.next().unwrap_or_default().to_string()));
+                let text: Vec<Line> = std::iter::once(Line::from(first))
+                    .chain(lines.map(|line| Line::from(format!("{}{}", indent, line))))
+                    .collect();
+
+                let item = match selected {
+                    true => ListItem::new(text).style(Style::new().fg(Color::Green)),
+                    false => ListItem::new(text),
+                };
+                self.list.push(item.clone());
+                items.push(item);
+            }
+        }
+
+        self.state.select(selected_row);
+        frame.render_stateful_widget(List::new(items), area, &mut self.state);
+    }
+
     pub fn up(&mut self) {
         if self.index > 0 {
             self.index -= 1;
         }
     }
 
-    pub fn right(&mut self) {
-        self.down();
-    }
-
     pub fn down(&mut self) {
-        if self.list.len() > 0 {
-            if self.index < self.list.len() - 1 {
-                self.index += 1;
-            }
+        if !self.list.is_empty() && self.index < self.list.len() - 1 {
+            self.index += 1;
         }
     }
 
-    pub fn left(&mut self) {
-        self.up();
+    pub fn top(&mut self) {
+        self.index = 0;
     }
 
-    pub fn key_binding(&mut self, key: KeyEvent) {
-        match key.code {
-            KeyCode::Up => self.up(),
-            KeyCode::Right => self.right(),
-            KeyCode::Down => self.down(),
-            KeyCode::Left => self.left(),
-            _ => {},
-        }
+    pub fn bottom(&mut self) {
+        self.index = self.list.len().saturating_sub(1);
     }
-}
\ No newline at end of file
+
+    pub fn clamp(&mut self, len: usize) {
+        self.index = self.index.min(len.saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use super::*;
+
+    fn draw(list: &mut SelectList, height: u16, groups: Vec<(String, Vec<ColoredItem>)>) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(30, height)).unwrap();
+        terminal.draw(|frame| list.render_grouped(frame, frame.area(), groups)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect::<String>().trim_end().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn scrolls_to_keep_the_selected_item_visible() {
+        let mut list = SelectList::new();
+        let groups = || vec![
+            ("Mon".to_string(), vec![(None, "A".to_string()), (None, "B".to_string())]),
+            ("Tue".to_string(), vec![(None, "C".to_string()), (None, "D".to_string())]),
+        ];
+        draw(&mut list, 3, groups());
+        list.bottom();
+
+        assert_eq!(draw(&mut list, 3, groups()), ["Tue", "■ C", "> ■ D"]);
+    }
+
+    #[test]
+    fn renders_multi_line_items_aligned() {
+        let mut list = SelectList::new();
+        let groups = vec![("Mon".to_string(), vec![
+            (None, "Standup\n  09:00 - 09:15".to_string()),
+            (None, "Review\n  10:00 - 11:00".to_string()),
+        ])];
+
+        assert_eq!(draw(&mut list, 5, groups), [
+            "Mon",
+            "> ■ Standup",
+            "      09:00 - 09:15",
+            "■ Review",
+            "    10:00 - 11:00",
+        ]);
+    }
+}
@@ -0,0 +1,154 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Timelike};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+const HOUR_WIDTH: u16 = 6;
+const FIRST_HOUR: u32 = 8;
+const LAST_HOUR: u32 = 20;
+const ROW_MINUTES: [u32; 6] = [15, 30, 60, 120, 180, 360];
+
+#[derive(Debug, Clone)]
+pub struct DayEvent {
+    pub date: NaiveDate,
+    pub last_date: NaiveDate,
+    pub start: Option<NaiveTime>,
+    pub end: Option<NaiveTime>,
+    pub title: String,
+    pub color: Option<Color>,
+    pub selected: bool,
+}
+
+impl DayEvent {
+    pub fn is_all_day(&self) -> bool {
+        self.start.is_none()
+    }
+
+    pub fn is_on(&self, date: NaiveDate) -> bool {
+        self.date <= date && date <= self.last_date
+    }
+
+    fn minutes(&self) -> Option<(u32, u32)> {
+        let start = self.start?;
+        let start = start.hour() * 60 + start.minute();
+        let end = self.end
+            .map(|end| end.hour() * 60 + end.minute())
+            .filter(|end| *end > start)
+            .unwrap_or(24 * 60);
+
+        Some((start, end))
+    }
+}
+
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+}
+
+#[derive(Debug)]
+pub struct WeekView<'a> {
+    pub start: NaiveDate,
+    pub cursor: NaiveDate,
+    pub events: &'a [DayEvent],
+}
+
+impl WeekView<'_> {
+    fn hours(&self) -> (u32, u32) {
+        self.events
+            .iter()
+            .filter_map(|event| event.minutes())
+            .fold((FIRST_HOUR, LAST_HOUR), |(first, last), (start, end)| {
+                (first.min(start / 60), last.max(end.div_ceil(60)))
+            })
+    }
+}
+
+impl Widget for WeekView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width <= HOUR_WIDTH || area.height < 3 {
+            return;
+        }
+
+        let column_width = (area.width - HOUR_WIDTH) / 7;
+        if column_width == 0 {
+            return;
+        }
+        let column_x = |day: u16| area.x + HOUR_WIDTH + day * column_width;
+
+        let (first_hour, last_hour) = self.hours();
+        let rows = area.height - 2;
+        let span = (last_hour - first_hour) * 60;
+        let minutes_per_row = ROW_MINUTES
+            .into_iter()
+            .find(|minutes| span.div_ceil(*minutes) <= u32::from(rows))
+            .unwrap_or(24 * 60);
+        let row_of = |minutes: u32| {
+            let minutes = minutes.saturating_sub(first_hour * 60);
+            (minutes / minutes_per_row).min(u32::from(rows) - 1) as u16
+        };
+
+        for day in 0..7 {
+            let date = self.start + Duration::days(i64::from(day));
+            let style = if date == self.cursor {
+                Style::new().fg(Color::Green).add_modifier(Modifier::BOLD)
+            } else {
+                Style::new().add_modifier(Modifier::BOLD)
+            };
+            let header = date.format("%a %m/%d").to_string();
+            buf.set_stringn(column_x(day), area.y, header, usize::from(column_width - 1), style);
+
+            let all_day: Vec<&DayEvent> = self.events
+                .iter()
+                .filter(|event| event.is_all_day() && event.is_on(date))
+                .collect();
+            if let Some(first) = all_day.first() {
+                let text = if all_day.len() > 1 {
+                    format!("{} +{}", first.title, all_day.len() - 1)
+                } else {
+                    first.title.clone()
+                };
+                let style = event_style(first.color, all_day.iter().any(|event| event.selected));
+                buf.set_stringn(column_x(day), area.y + 1, text, usize::from(column_width - 1), style);
+            }
+        }
+
+        for row in 0..rows {
+            let minutes = first_hour * 60 + u32::from(row) * minutes_per_row;
+            if minutes < last_hour * 60 && minutes % 60 == 0 {
+                let label = format!("{:02}:00", minutes / 60);
+                buf.set_stringn(area.x, area.y + 2 + row, label, usize::from(HOUR_WIDTH - 1), Style::new().fg(Color::DarkGray));
+            }
+        }
+
+        let mut timed: Vec<&DayEvent> = self.events.iter().filter(|event| !event.is_all_day()).collect();
+        timed.sort_by_key(|event| event.selected);
+
+        for event in timed {
+            let day = (event.date - self.start).num_days();
+            let Some((start, end)) = event.minutes().filter(|_| (0..7).contains(&day)) else {
+                continue;
+            };
+
+            let first_row = row_of(start);
+            let last_row = row_of(end.saturating_sub(1)).max(first_row);
+            let x = column_x(day as u16);
+            let width = column_width.saturating_sub(1);
+            let style = event_style(event.color, event.selected);
+
+            for row in first_row..=last_row {
+                let text = if row == first_row { event.title.as_str() } else { "" };
+                buf.set_stringn(x, area.y + 2 + row, format!("{:<width$}", text, width = usize::from(width)), usize::from(width), style);
+            }
+        }
+    }
+}
+
+fn event_style(color: Option<Color>, selected: bool) -> Style {
+    if selected {
+        Style::new().fg(Color::Black).bg(Color::Green)
+    } else {
+        Style::new().fg(Color::Black).bg(color.unwrap_or(Color::Blue))
+    }
+}
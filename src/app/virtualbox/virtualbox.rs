@@ -1,88 +1,241 @@
-use core::str;
-use std::process::Command;
+use std::{io, process::Command};
 
+use chrono::Local;
 use ratatui::{
-    crossterm::event::{KeyCode, KeyEvent},
+    crossterm::event::KeyEvent,
     layout::Rect, 
     Frame,
 };
 
+use crate::app::config::{self, Config};
+use crate::app::keymap::{Action, Context};
+use crate::app::tab::TabApp;
 use crate::app::ui::{
-    help::Help,
+    help::{HelpItem, HelpSection},
     pane::Pane,
     select_list::SelectList,
 };
+use crate::app::interval::Interval;
+use crate::app::notification::Notifier;
+use crate::app::worker::Worker;
 
-const VIRTUALBOX_PATH: &str = "/mnt/c/Program Files/Oracle/VirtualBox/VBoxManage.exe";
 const APP_TITLE: &str = "VirtualBox";
-const HELP_TITLE: &str = "Help VirtualBox";
+const RUNNING: &str = "running";
+const POWERED_OFF: &str = "powered off";
+
+#[derive(Debug, thiserror::Error)]
+pub enum VirtualBoxError {
+    #[error("Failed to list machines: {0}")]
+    List(io::Error),
+    #[error("Failed to start {machine}: {source}")]
+    Start {
+        machine: String,
+        source: io::Error,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Machine {
+    pub name: String,
+    pub uuid: String,
+    pub running: bool,
+}
+
+impl Machine {
+    fn value(&self) -> String {
+        format!("{} ({})", self.name, if self.running { RUNNING } else { POWERED_OFF })
+    }
+}
 
 #[derive(Debug)]
 pub struct VirtualBox<'a> {
     pub pane: Pane,
-    pub machines: Vec<String>,
+    pub machines: Vec<Machine>,
     pub select_list: SelectList<'a>,
-    pub help: Help,
+    command: String,
+    stale: bool,
+    worker: Worker<Result<Vec<Machine>, VirtualBoxError>>,
+    interval: Interval,
+    notifier: Notifier,
 }
 
 impl<'a> VirtualBox<'a> {
-    pub fn new() -> Self {
+    pub fn new(config: &Config, notifier: &Notifier) -> Self {
         Self {
             pane: Pane::new(APP_TITLE),
-            machines: Self::read(),
+            machines: Vec::new(),
             select_list: SelectList::new(),
-            help: Help::new(HELP_TITLE),
+            command: config.virtualbox.command.clone(),
+            stale: false,
+            worker: Worker::new(),
+            interval: Interval::from_secs(config.refresh.virtualbox),
+            notifier: notifier.clone(),
         }
     }
 
-    fn read() -> Vec<String> {
-        let output = Command::new(VIRTUALBOX_PATH)
-            .arg("list")
-            .arg("vms")
-            .output()
-            .expect("Failed to execute read_machines");
-
-        let output_str = str::from_utf8(&output.stdout).expect("Invalid UTF-8 output");
-
-        output_str
-            .lines()
-            .filter_map(|line| {
-                if let Some(start) = line.find('"') {
-                    if let Some(end) = line[start + 1..].find('"') {
-                        Some(line[start + 1..start + 1 + end].to_string())
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
+    fn read(command: &str) -> io::Result<Vec<Machine>> {
+        let machines = Self::list(command, "vms")?;
+        let running = Self::list(command, "runningvms")?;
+
+        Ok(machines
+            .into_iter()
+            .map(|(name, uuid)| Machine {
+                running: running.iter().any(|(_, running)| *running == uuid),
+                name,
+                uuid,
             })
-            .collect()
+            .collect())
+    }
+
+    fn list(command: &str, kind: &str) -> io::Result<Vec<(String, String)>> {
+        let output = Command::new(command)
+            .arg("list")
+            .arg(kind)
+            .output()?;
+        config::check_status(command, &output)?;
+
+        Ok(parse_list(&String::from_utf8_lossy(&output.stdout)))
     }
 
-    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+    pub fn open(&mut self) {
+        let machine = self.machines[self.select_list.index].name.to_string();
+        let command = self.command.clone();
+        let notifier = self.notifier.clone();
+
+        self.worker.spawn(move || {
+            let output = Command::new(&command)
+                .arg("startvm")
+                .arg(&machine)
+                .arg("--type")
+                .arg("gui")
+                .output();
+            output
+                .and_then(|output| config::check_status(&command, &output))
+                .map_err(|source| VirtualBoxError::Start { machine: machine.clone(), source })?;
+            notifier.info(format!("Started {}", machine));
+
+            VirtualBox::read(&command).map_err(VirtualBoxError::List)
+        });
+    }
+}
+
+impl<'a> TabApp for VirtualBox<'a> {
+    fn title(&self) -> &str {
+        APP_TITLE
+    }
+
+    fn pane(&mut self) -> &mut Pane {
+        &mut self.pane
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
         let pane = self.pane.render(frame, area);
 
-        self.select_list.render(frame, pane, self.machines.clone());
+        self.select_list.render(frame, pane, self.machines.iter().map(Machine::value).collect());
+    }
+
+    fn render_popup(&mut self, _frame: &mut Frame) {}
+
+    fn on_action(&mut self, action: Action) {
+        match action {
+            Action::Open if !self.machines.is_empty() => {
+                self.open();
+            },
+            Action::Refresh => self.refresh(),
+            Action::Up | Action::Left => self.select_list.up(),
+            Action::Down | Action::Right => self.select_list.down(),
+            Action::Top => self.select_list.top(),
+            Action::Bottom => self.select_list.bottom(),
+            _ => {},
+        }
     }
 
-    pub fn open(&self) {
-        let machine = self.machines[self.select_list.index].to_string();
+    fn on_input(&mut self, _key: KeyEvent) {}
+
+    fn help(&self) -> Vec<HelpSection> {
+        vec![
+            HelpSection::new("Select VirtualBox", Context::List, vec![
+                HelpItem::Action(Action::Open, "Open Machine"),
+                HelpItem::Action(Action::Up, "Focus Move Up"),
+                HelpItem::Action(Action::Down, "Focus Move Down"),
+                HelpItem::Action(Action::Top, "Focus Move Top"),
+                HelpItem::Action(Action::Bottom, "Focus Move Bottom"),
+            ]),
+        ]
+    }
 
-        Command::new(VIRTUALBOX_PATH)
-            .arg("startvm")
-            .arg(machine)
-            .arg("--type")
-            .arg("gui")
-            .output()
-            .expect("Failed to execute open_virtualbox");
+    fn has_open_popup(&self) -> bool {
+        false
     }
 
-    pub fn key_binding(&mut self, key: KeyEvent) {
-        match key.code {
-            KeyCode::F(1) => self.help.popup.active = true,
-            KeyCode::Enter => self.open(),
-            _ => self.select_list.key_binding(key),
+    fn close_popup(&mut self) {}
+
+    fn refresh(&mut self) {
+        self.interval.reset();
+        if self.worker.is_busy() {
+            self.stale = true;
+            return;
+        }
+
+        self.stale = false;
+
+        let command = self.command.clone();
+
+        self.worker.spawn(move || VirtualBox::read(&command).map_err(VirtualBoxError::List));
+    }
+
+    fn tick(&mut self) {
+        while let Some(result) = self.worker.try_recv() {
+            match result {
+                Ok(machines) => {
+                    self.machines = machines;
+                    self.select_list.clamp(self.machines.len());
+                    self.pane.synced = Some(Local::now());
+                },
+                Err(err) => self.notifier.error(err.to_string()),
+            }
+        }
+
+        self.pane.loading = self.worker.is_busy();
+
+        if (self.stale || self.interval.is_due()) && !self.pane.loading {
+            self.refresh();
         }
     }
-}
\ No newline at end of file
+}
+
+fn parse_list(output: &str) -> Vec<(String, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, uuid) = line.rsplit_once(' ')?;
+            let name = name.strip_prefix('"')?.strip_suffix('"')?;
+            let uuid = uuid.strip_prefix('{')?.strip_suffix('}')?;
+
+            Some((name.to_string(), uuid.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_machine_lists() {
+        let output = "\"Ubuntu 24.04\" {0b1c-01}\n\"win\" {0b1c-02}\nnoise\n";
+
+        assert_eq!(parse_list(output), [
+            ("Ubuntu 24.04".to_string(), "0b1c-01".to_string()),
+            ("win".to_string(), "0b1c-02".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn shows_machine_state() {
+        let machine = Machine { name: "win".to_string(), uuid: "0b1c-02".to_string(), running: true };
+
+        assert_eq!(machine.value(), "win (running)");
+        assert_eq!(Machine { running: false, ..machine }.value(), "win (powered off)");
+    }
+}
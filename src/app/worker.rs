@@ -0,0 +1,44 @@
+use std::{
+    sync::mpsc::{self, Receiver, Sender},
+    thread::{self, JoinHandle},
+};
+
+#[derive(Debug)]
+pub struct Worker<T> {
+    sender: Sender<T>,
+    receiver: Receiver<T>,
+    jobs: Vec<JoinHandle<()>>,
+}
+
+impl<T: Send + 'static> Worker<T> {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        Self {
+            sender,
+            receiver,
+            jobs: Vec::new(),
+        }
+    }
+
+    pub fn spawn<F>(&mut self, job: F)
+    where
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let sender = self.sender.clone();
+
+        self.jobs.push(thread::spawn(move || {
+            let _ = sender.send(job());
+        }));
+    }
+
+    pub fn try_recv(&mut self) -> Option<T> {
+        self.receiver.try_recv().ok()
+    }
+
+    pub fn is_busy(&mut self) -> bool {
+        self.jobs.retain(|job| !job.is_finished());
+
+        !self.jobs.is_empty()
+    }
+}
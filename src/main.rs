@@ -1,16 +1,21 @@
+#![allow(clippy::module_inception)]
+
 mod app;
 
 use color_eyre::Result;
 
 use app::app::App;
+use app::config::Config;
 use app::google::authentication::Authentication;
 
 fn main() -> Result<()> {
-    let authentication = Authentication::new();
-
     color_eyre::install()?;
+
+    let config = Config::load()?;
+    let authentication = Authentication::new(&config)?;
+
     let terminal = ratatui::init();
-    let app_result = App::new(&authentication.token_info).run(terminal);
+    let app_result = App::new(&config, authentication.accounts).run(terminal);
     ratatui::restore();
     app_result
 }